@@ -0,0 +1,134 @@
+use serde_json::Value;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const CODES_JSON: &str = "data/codes.json";
+
+fn main() {
+    embed_version();
+    generate_codes();
+}
+
+// Embeds the version shown by `--version`, with the short git commit when
+// building from a checkout.
+fn embed_version() {
+    let version = env::var("CARGO_PKG_VERSION").unwrap_or_default();
+    let commit = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|hash| hash.trim().to_string())
+        .filter(|hash| !hash.is_empty());
+
+    match commit {
+        Some(hash) => println!("cargo:rustc-env=HTTPSTATUS_VERSION={} ({})", version, hash),
+        None => println!("cargo:rustc-env=HTTPSTATUS_VERSION={}", version),
+    }
+
+    for path in [".git/HEAD", ".git/refs/heads"] {
+        if Path::new(path).exists() {
+            println!("cargo:rerun-if-changed={}", path);
+        }
+    }
+}
+
+// Turns the status code data into `STATUSES` and `UNOFFICIAL_STATUSES`
+// arrays, included by `src/codes.rs`.
+fn generate_codes() {
+    println!("cargo:rerun-if-changed={}", CODES_JSON);
+    let text = fs::read_to_string(CODES_JSON)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", CODES_JSON, e));
+    let data: Value = serde_json::from_str(&text)
+        .unwrap_or_else(|e| panic!("{} is not valid JSON: {}", CODES_JSON, e));
+
+    let mut code = String::new();
+    for (key, name, source) in [
+        ("registered", "STATUSES", "Iana"),
+        ("unofficial", "UNOFFICIAL_STATUSES", "Unofficial"),
+    ] {
+        let statuses = data[key]
+            .as_array()
+            .unwrap_or_else(|| panic!("{} has no \"{}\" list", CODES_JSON, key));
+        writeln!(code, "static {}: &[Status] = &[", name).unwrap();
+        for status in statuses {
+            writeln!(code, "    {},", status_literal(status, source)).unwrap();
+        }
+        writeln!(code, "];").unwrap();
+    }
+
+    // The registered codes again as plain pairs, for lookups without the map.
+    writeln!(code, "const SORTED_PAIRS: &[(u16, &str)] = &[").unwrap();
+    for status in data["registered"].as_array().into_iter().flatten() {
+        writeln!(
+            code,
+            "    ({}, {:?}),",
+            status["code"],
+            status["description"].as_str().unwrap_or_default()
+        )
+        .unwrap();
+    }
+    writeln!(code, "];").unwrap();
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("codes.rs"), code).expect("Failed to write codes.rs");
+}
+
+fn status_literal(status: &Value, source: &str) -> String {
+    let text = |field: &str| {
+        status[field]
+            .as_str()
+            .unwrap_or_else(|| panic!("{} entry {} has no \"{}\"", CODES_JSON, status, field))
+    };
+    let code = status["code"]
+        .as_u64()
+        .filter(|code| (100..=999).contains(code))
+        .unwrap_or_else(|| panic!("{} entry {} has no valid \"code\"", CODES_JSON, status));
+    let list = |field: &str| -> Vec<&str> {
+        match &status[field] {
+            Value::Null => Vec::new(),
+            Value::Array(items) => items
+                .iter()
+                .map(|item| {
+                    item.as_str().unwrap_or_else(|| {
+                        panic!(
+                            "{} entry {} has a non-string item in \"{}\"",
+                            CODES_JSON, status, field
+                        )
+                    })
+                })
+                .collect(),
+            _ => panic!(
+                "{} entry {} has an invalid \"{}\"",
+                CODES_JSON, status, field
+            ),
+        }
+    };
+    let retryable = status["retryable"].as_bool().unwrap_or(false);
+    let retry_advice = status["retry_advice"].as_str().unwrap_or_default();
+    if retryable == retry_advice.is_empty() {
+        panic!(
+            "{} entry {} must have \"retry_advice\" exactly when it is \"retryable\"",
+            CODES_JSON, status
+        );
+    }
+    format!(
+        "Status {{ code: {}, description: {:?}, rfc: {:?}, explanation: {:?}, related_methods: &{:?}, protocol_notes: {:?}, example: {:?}, header_examples: &{:?}, retryable: {}, retry_advice: {:?}, deprecated: {}, source: Source::{} }}",
+        code,
+        text("description"),
+        text("rfc"),
+        text("explanation"),
+        list("related_methods"),
+        status["protocol_notes"].as_str().unwrap_or_default(),
+        status["example"].as_str().unwrap_or_default(),
+        list("header_examples"),
+        retryable,
+        retry_advice,
+        status["deprecated"].as_bool().unwrap_or(false),
+        source
+    )
+}
@@ -0,0 +1,66 @@
+//! Informal names for well-known status codes, such as "teapot" for 418.
+//! Unlike a description search these resolve to exactly one code.
+
+static ALIASES: &[(&str, u16)] = &[
+    ("empty", 204),
+    ("moved", 301),
+    ("redirect", 302),
+    ("unauthenticated", 401),
+    ("denied", 403),
+    ("not found", 404),
+    ("too large", 413),
+    ("teapot", 418),
+    ("unprocessable", 422),
+    ("rate limit", 429),
+    ("rate limited", 429),
+    ("throttled", 429),
+    ("censored", 451),
+    ("ise", 500),
+    ("server error", 500),
+    ("maintenance", 503),
+];
+
+/// Resolves an informal name to its status code. Case, and whether words are
+/// separated by spaces, hyphens or underscores, do not matter.
+///
+/// ```
+/// assert_eq!(httpstatus_rust::alias_code("Rate-Limit"), Some(429));
+/// ```
+pub fn alias_code(name: &str) -> Option<u16> {
+    let name = name
+        .to_lowercase()
+        .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    ALIASES
+        .iter()
+        .find(|&&(alias, _)| alias == name)
+        .map(|&(_, code)| code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ALIASES, alias_code};
+    use crate::status_codes;
+
+    #[test]
+    fn test_alias_code() {
+        assert_eq!(alias_code("teapot"), Some(418));
+        assert_eq!(alias_code("not found"), Some(404));
+        assert_eq!(alias_code("  Rate_Limit "), Some(429));
+        assert_eq!(alias_code("banana"), None);
+    }
+
+    #[test]
+    fn test_aliases_point_at_registered_codes() {
+        for &(alias, code) in ALIASES {
+            assert!(
+                status_codes().contains_key(&code),
+                "Alias '{}' points at unregistered code {}",
+                alias,
+                code
+            );
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! Classification of status codes by their hundreds digit. These work for any
+//! `u16`, whether or not the code is registered.
+
+use crate::status_codes;
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+static CLASS_COUNTS: LazyLock<BTreeMap<u8, usize>> = LazyLock::new(|| {
+    let mut counts: BTreeMap<u8, usize> = (1..=5).map(|hundreds| (hundreds, 0)).collect();
+    for &code in status_codes().keys() {
+        // Registered codes lie in 100..=599, so the digit fits a u8.
+        *counts.entry((code / 100) as u8).or_default() += 1;
+    }
+    counts
+});
+
+/// Returns true for 1xx codes.
+pub const fn is_informational(code: u16) -> bool {
+    matches!(code, 100..=199)
+}
+
+/// Returns true for 2xx codes.
+pub const fn is_success(code: u16) -> bool {
+    matches!(code, 200..=299)
+}
+
+/// Returns true for 3xx codes.
+pub const fn is_redirection(code: u16) -> bool {
+    matches!(code, 300..=399)
+}
+
+/// Returns true for redirects that replace the URL for good (301 and 308), so
+/// clients and search engines should update stored links.
+pub const fn is_permanent_redirect(code: u16) -> bool {
+    matches!(code, 301 | 308)
+}
+
+/// Returns true for redirects that only apply to this request (302, 303 and
+/// 307), so clients should keep using the original URL.
+pub const fn is_temporary_redirect(code: u16) -> bool {
+    matches!(code, 302 | 303 | 307)
+}
+
+/// Returns true for 4xx codes.
+pub const fn is_client_error(code: u16) -> bool {
+    matches!(code, 400..=499)
+}
+
+/// Returns true for 5xx codes.
+pub const fn is_server_error(code: u16) -> bool {
+    matches!(code, 500..=599)
+}
+
+/// Returns the canonical name of the code's class, such as "Client Error" for
+/// 404, or None outside 100-599.
+pub const fn class_name(code: u16) -> Option<&'static str> {
+    match code {
+        100..=199 => Some("Informational"),
+        200..=299 => Some("Success"),
+        300..=399 => Some("Redirection"),
+        400..=499 => Some("Client Error"),
+        500..=599 => Some("Server Error"),
+        _ => None,
+    }
+}
+
+/// Returns a sentence on what the code's class as a whole means, or None
+/// outside 100-599.
+///
+/// ```
+/// let summary = httpstatus_rust::describe_class(404).unwrap();
+/// assert!(summary.starts_with("Client errors"));
+/// ```
+pub const fn describe_class(code: u16) -> Option<&'static str> {
+    match code {
+        100..=199 => Some(
+            "Informational responses are interim: the server has received the request and is still working on it, and a final response will follow.",
+        ),
+        200..=299 => Some(
+            "Successful responses indicate that the request was received, understood and accepted.",
+        ),
+        300..=399 => Some(
+            "Redirection responses indicate that the client must take further action, usually following the `Location` header, to complete the request.",
+        ),
+        400..=499 => Some(
+            "Client errors indicate that the request contains bad syntax or cannot be fulfilled; the client should change it before retrying.",
+        ),
+        500..=599 => Some(
+            "Server errors indicate that the server failed to fulfil an apparently valid request; the same request may succeed later.",
+        ),
+        _ => None,
+    }
+}
+
+/// Returns the registered codes whose hundreds digit is `hundreds`, with their
+/// reason phrases, in ascending order. Empty for classes with no codes.
+///
+/// ```
+/// let redirects = httpstatus_rust::codes_in_class(3);
+/// assert_eq!(redirects.first(), Some(&(300, "Multiple Choices")));
+/// ```
+pub fn codes_in_class(hundreds: u8) -> Vec<(u16, &'static str)> {
+    let start = u16::from(hundreds) * 100;
+    status_codes()
+        .range(start..start + 100)
+        .map(|(&code, status)| (code, status.description))
+        .collect()
+}
+
+/// Returns how many registered codes each class has, keyed by hundreds digit
+/// from 1 to 5. The counts are computed once and then shared.
+///
+/// ```
+/// assert_eq!(httpstatus_rust::class_counts()[&1], 4);
+/// ```
+pub fn class_counts() -> &'static BTreeMap<u8, usize> {
+    &CLASS_COUNTS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        class_counts, class_name, codes_in_class, describe_class, is_client_error,
+        is_informational, is_permanent_redirect, is_redirection, is_server_error, is_success,
+        is_temporary_redirect,
+    };
+    use crate::status_codes;
+    use crate::validate::EXPECTED_CLASS_COUNTS;
+
+    #[test]
+    fn test_codes_in_class() {
+        let counts: Vec<usize> = (1..=5).map(|class| codes_in_class(class).len()).collect();
+        assert_eq!(counts, EXPECTED_CLASS_COUNTS);
+        assert_eq!(codes_in_class(4)[0], (400, "Bad Request"));
+        assert!(codes_in_class(0).is_empty());
+        assert!(codes_in_class(6).is_empty());
+        assert!(codes_in_class(u8::MAX).is_empty());
+    }
+
+    #[test]
+    fn test_class_counts() {
+        let counts: Vec<(u8, usize)> = class_counts().iter().map(|(&k, &v)| (k, v)).collect();
+        let expected: Vec<(u8, usize)> = (1..=5).zip(EXPECTED_CLASS_COUNTS).collect();
+        assert_eq!(counts, expected);
+        assert_eq!(class_counts().values().sum::<usize>(), status_codes().len());
+        assert!(std::ptr::eq(class_counts(), class_counts()));
+    }
+
+    #[test]
+    fn test_class_boundaries() {
+        assert!(!is_informational(99));
+        assert!(is_informational(100));
+        assert!(is_informational(199));
+        assert!(is_success(200));
+        assert!(is_success(299));
+        assert!(is_redirection(300));
+        assert!(is_redirection(399));
+        assert!(is_client_error(400));
+        assert!(is_client_error(499));
+        assert!(is_server_error(500));
+        assert!(is_server_error(599));
+        assert!(!is_server_error(600));
+    }
+
+    #[test]
+    fn test_unregistered_codes_are_classified() {
+        assert!(is_client_error(499));
+        assert!(is_server_error(520));
+        assert!(is_success(299));
+    }
+
+    #[test]
+    fn test_every_code_has_exactly_one_class() {
+        let classifiers = [
+            is_informational,
+            is_success,
+            is_redirection,
+            is_client_error,
+            is_server_error,
+        ];
+        for &code in status_codes().keys() {
+            let matches = classifiers.iter().filter(|is| is(code)).count();
+            assert_eq!(matches, 1, "Code {} should be in exactly one class", code);
+        }
+    }
+
+    #[test]
+    fn test_redirect_permanence() {
+        let permanent: Vec<u16> = (300..400).filter(|&c| is_permanent_redirect(c)).collect();
+        let temporary: Vec<u16> = (300..400).filter(|&c| is_temporary_redirect(c)).collect();
+        assert_eq!(permanent, [301, 308]);
+        assert_eq!(temporary, [302, 303, 307]);
+        assert!(!is_permanent_redirect(304) && !is_temporary_redirect(304));
+    }
+
+    #[test]
+    fn test_class_name() {
+        assert_eq!(class_name(100), Some("Informational"));
+        assert_eq!(class_name(204), Some("Success"));
+        assert_eq!(class_name(308), Some("Redirection"));
+        assert_eq!(class_name(418), Some("Client Error"));
+        assert_eq!(class_name(599), Some("Server Error"));
+        assert_eq!(class_name(99), None);
+        assert_eq!(class_name(600), None);
+    }
+
+    #[test]
+    fn test_describe_class() {
+        for code in [100, 250, 399, 404, 599] {
+            assert!(describe_class(code).is_some_and(|summary| summary.ends_with('.')));
+        }
+        assert_eq!(describe_class(99), None);
+        assert_eq!(describe_class(600), None);
+    }
+}
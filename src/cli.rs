@@ -0,0 +1,801 @@
+use clap::{ArgGroup, CommandFactory, Parser, ValueEnum};
+use clap_complete::Shell;
+use httpstatus_rust::{alias_code, class_name};
+use serde::Deserialize;
+use std::io;
+use std::path::PathBuf;
+
+const AFTER_HELP: &str = "\
+Environment:
+  HTTPSTATUS_FORMAT  Output format to use when no format flag is given
+                     (table, json, minimal-json, jsonl, csv, tsv, yaml,
+                     markdown, html, xml, toml, plain, only-codes).
+                     --format or a format flag always takes precedence.
+                     Without any of these, the table is printed on a
+                     terminal and plain lines otherwise.
+  NO_COLOR           Disable colors when set to a non-empty value
+
+Config file:
+  ~/.config/httpstatus/config.toml (or under $XDG_CONFIG_HOME) can set
+  format, theme and extended. Flags and HTTPSTATUS_FORMAT take precedence.
+
+Exit status when looking up a single CODE:
+  0  2xx Success
+  1  1xx Informational
+  3  3xx Redirection
+  4  4xx Client Error
+  5  5xx Server Error
+  6  A code outside 1xx-5xx, such as a 6xx code from --codes-file
+  2  Unknown status code
+
+When looking up several codes, unknown codes are reported and the exit
+status is 2 if any of them was unknown.
+
+With --is-valid, the exit status is 0 for a known code and 1 otherwise.
+
+With --diff, the exit status is 0 when the lists match, 1 when they differ
+and 2 if the file cannot be read.";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Format {
+    Table,
+    Json,
+    MinimalJson,
+    Jsonl,
+    Csv,
+    Tsv,
+    Yaml,
+    Markdown,
+    Html,
+    Xml,
+    Toml,
+    Plain,
+    OnlyCodes,
+}
+
+/// A column of the table output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Column {
+    Code,
+    Description,
+    Rfc,
+    Explanation,
+    /// `iana` or `unofficial`
+    Source,
+}
+
+/// A positional lookup: one code, or an inclusive range such as `400-410`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Lookup {
+    Code(u16),
+    Range(u16, u16),
+}
+
+/// The border style of tables.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Border {
+    /// An outer frame and a line below the header
+    #[default]
+    BordersOnly,
+    /// Lines around every cell
+    Full,
+    /// Lines around every cell, drawn with ASCII characters
+    Ascii,
+    /// An outer frame and a header line, drawn with ASCII characters
+    AsciiBordersOnly,
+    /// No lines at all
+    None,
+}
+
+/// When to use colors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum ColorWhen {
+    /// Only when writing to a terminal
+    #[default]
+    Auto,
+    /// Even when piped, but never in an --output file
+    Always,
+    /// Never, even on a terminal
+    Never,
+}
+
+/// A color preset for tables.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    /// Blue, green, yellow, magenta and red rows by class
+    #[default]
+    Default,
+    /// Shades of grey, brighter for 4xx and 5xx
+    Mono,
+    /// The Solarized accent colors (needs a true-color terminal)
+    Solarized,
+}
+
+/// The order rows are printed in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum SortKey {
+    #[default]
+    Code,
+    /// Alphabetically by description
+    Name,
+}
+
+/// Prints HTTP status codes and their descriptions.
+#[derive(Parser)]
+#[command(
+    name = "httpstatus",
+    version = env!("HTTPSTATUS_VERSION"),
+    after_help = AFTER_HELP,
+    group(ArgGroup::new("format").args(["output_format", "json", "raw_json", "minimal_json", "jsonl", "csv", "tsv", "yaml", "markdown", "html", "xml", "toml", "plain", "only_codes", "force_table"])),
+    group(ArgGroup::new("filter").args(["class", "search"]).multiple(true)),
+)]
+pub struct Cli {
+    /// Look up one or more status codes, ranges such as 400-410, or informal
+    /// names such as "teapot"
+    #[arg(value_name = "CODE", value_parser = parse_lookup)]
+    pub codes: Vec<Lookup>,
+
+    /// Output format; the flags below are shorthands for it
+    #[arg(long = "format", value_name = "FORMAT", value_enum)]
+    pub output_format: Option<Format>,
+
+    /// Print JSON (same as --format json)
+    #[arg(short, long)]
+    pub json: bool,
+
+    /// Print pretty-printed JSON (same as --json)
+    #[arg(long, visible_alias = "json-pretty")]
+    pub raw_json: bool,
+
+    /// Print compact single-line JSON
+    #[arg(long, visible_alias = "json-compact")]
+    pub minimal_json: bool,
+
+    /// Wrap JSON output as {"codes": ..., "summary": ...} with per-class counts
+    #[arg(long)]
+    pub summary: bool,
+
+    /// Print JSON values as self-describing objects with the code and class
+    /// (implies --json unless another JSON format is given)
+    #[arg(long)]
+    pub json_objects: bool,
+
+    /// Pretty-print JSON through jq when it is installed (implies --json)
+    #[cfg(feature = "external-jq")]
+    #[arg(long)]
+    pub jq: bool,
+
+    /// Print a JSON Schema of the JSON output selected by --rfc, --extended,
+    /// --summary and --json-objects
+    #[arg(long)]
+    pub schema: bool,
+
+    /// Print one JSON object per line (JSON Lines)
+    #[arg(long)]
+    pub jsonl: bool,
+
+    /// Print CSV
+    #[arg(long)]
+    pub csv: bool,
+
+    /// Print tab-separated `code<TAB>description` lines
+    #[arg(long)]
+    pub tsv: bool,
+
+    /// Print YAML
+    #[arg(long)]
+    pub yaml: bool,
+
+    /// Print a GitHub-flavored Markdown table
+    #[arg(long)]
+    pub markdown: bool,
+
+    /// Print an HTML table
+    #[arg(long)]
+    pub html: bool,
+
+    /// Print XML
+    #[arg(long)]
+    pub xml: bool,
+
+    /// Print TOML
+    #[arg(long)]
+    pub toml: bool,
+
+    /// Print plain `code description` lines without table borders
+    #[arg(long)]
+    pub plain: bool,
+
+    /// Print the table even when stdout is not a terminal, instead of plain lines
+    #[arg(long)]
+    pub force_table: bool,
+
+    /// Print only the code numbers, one per line
+    #[arg(long)]
+    pub only_codes: bool,
+
+    /// Only show one class: 1xx to 5xx, or a name such as "client error"
+    #[arg(long, value_name = "CLASS", value_parser = parse_class)]
+    pub class: Option<u16>,
+
+    /// Describe what a whole class means: 1xx, 2xx, 3xx, 4xx or 5xx
+    #[arg(long, value_name = "CLASS", value_parser = parse_class, conflicts_with_all = ["codes", "class", "format"])]
+    pub class_info: Option<u16>,
+
+    /// Only show codes whose description contains TERM
+    #[arg(long, value_name = "TERM")]
+    pub search: Option<String>,
+
+    /// Show the codes that --class or --search leave out instead
+    #[arg(long, requires = "filter")]
+    pub invert: bool,
+
+    /// Explain when a status is used
+    #[arg(long, value_name = "CODE", value_parser = parse_code, conflicts_with = "codes")]
+    pub explain: Option<u16>,
+
+    /// Show a sample request and response for a status code
+    #[arg(long, value_name = "CODE", value_parser = parse_code, conflicts_with_all = ["codes", "explain", "format"])]
+    pub examples: Option<u16>,
+
+    /// Print nothing; exit 0 if CODE is a known status code or name, 1 otherwise
+    #[arg(long, value_name = "CODE", conflicts_with_all = ["codes", "explain", "stdin", "format"])]
+    pub is_valid: Option<String>,
+
+    /// Also copy the looked-up code, such as "404 Not Found", to the clipboard;
+    /// needs exactly one code
+    #[arg(long, requires = "codes")]
+    pub copy: bool,
+
+    /// Print the MDN documentation link for a status code
+    #[arg(long, value_name = "CODE", value_parser = parse_code, conflicts_with_all = ["codes", "explain"])]
+    pub help_code: Option<u16>,
+
+    /// Open the --help-code link in the default browser
+    #[arg(long, requires = "help_code")]
+    pub open: bool,
+
+    /// Compare the codes listed in FILE with the known codes
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["codes", "format", "grouped", "count", "prometheus", "random"]
+    )]
+    pub diff: Option<PathBuf>,
+
+    /// Print a separate table for each class
+    #[arg(long, conflicts_with = "format")]
+    pub grouped: bool,
+
+    /// Print how many codes each class has instead of the codes
+    #[arg(long, conflicts_with_all = ["format", "grouped"])]
+    pub count: bool,
+
+    /// Print the per-class counts as Prometheus metrics
+    #[arg(long, conflicts_with_all = ["format", "grouped", "count"])]
+    pub prometheus: bool,
+
+    /// Print a one-line summary such as "63 HTTP status codes across 5 classes"
+    #[arg(long, conflicts_with_all = ["format", "grouped", "count", "prometheus"])]
+    pub banner: bool,
+
+    /// Print one randomly chosen code (respects --class and --search)
+    #[arg(long, conflicts_with_all = ["codes", "grouped", "count", "prometheus"])]
+    pub random: bool,
+
+    /// Read whitespace-separated codes from stdin and describe each one
+    #[arg(long, conflicts_with_all = ["codes", "explain", "random", "count", "grouped", "format"])]
+    pub stdin: bool,
+
+    /// With --stdin, stop with exit status 2 at the first unknown code
+    #[arg(long, requires = "stdin")]
+    pub strict: bool,
+
+    /// Comma-separated table columns [default: code,description]
+    #[arg(long, value_name = "COLUMNS", value_enum, value_delimiter = ',')]
+    pub columns: Vec<Column>,
+
+    /// Wrap tables and explanations to this many characters [default: the
+    /// terminal width]
+    #[arg(long, value_name = "WIDTH", value_parser = clap::value_parser!(u16).range(20..))]
+    pub wrap: Option<u16>,
+
+    /// Write the results to FILE instead of stdout, without colors
+    #[arg(short, long, value_name = "FILE", conflicts_with = "interactive")]
+    pub output: Option<PathBuf>,
+
+    /// Show tables through $PAGER (default: less)
+    #[arg(long)]
+    pub pager: bool,
+
+    /// Order of the printed rows
+    #[arg(long, value_name = "KEY", value_enum, default_value_t)]
+    pub sort: SortKey,
+
+    /// Reverse the row order, e.g. to put 5xx first
+    #[arg(long)]
+    pub sort_desc: bool,
+
+    /// Only print the first N rows, after sorting and filtering
+    #[arg(long, value_name = "N")]
+    pub first: Option<usize>,
+
+    /// Only print the last N rows, after sorting and filtering
+    #[arg(long, value_name = "N", conflicts_with = "first")]
+    pub last: Option<usize>,
+
+    /// Look up codes or search terms interactively until EOF or `quit`
+    #[arg(
+        short,
+        long,
+        conflicts_with_all = ["codes", "explain", "stdin", "random", "count", "grouped", "format"]
+    )]
+    pub interactive: bool,
+
+    /// Include the defining RFC
+    #[arg(long)]
+    pub rfc: bool,
+
+    /// Leave out deprecated and reserved codes such as 305 and 306
+    #[arg(long)]
+    pub hide_deprecated: bool,
+
+    /// Show descriptions in another language, such as `es` or `de`
+    #[arg(long, value_name = "LANG")]
+    pub lang: Option<String>,
+
+    /// Include unofficial codes (nginx, Cloudflare, ...)
+    #[arg(long)]
+    pub extended: bool,
+
+    /// Add codes from a JSON file such as {"600": "Quota Exceeded"}
+    #[arg(long, value_name = "FILE")]
+    pub codes_file: Option<PathBuf>,
+
+    /// Check the data set, including --extended and --codes-file codes, and
+    /// report each invariant as ok or FAIL
+    #[arg(long, conflicts_with_all = ["codes", "format"])]
+    pub selfcheck: bool,
+
+    /// Leave out unofficial codes even if the config file includes them
+    #[arg(long, conflicts_with = "extended")]
+    pub no_extended: bool,
+
+    /// Prefix table descriptions with an emoji for their class, when colors are on
+    #[arg(long)]
+    pub emoji: bool,
+
+    /// Print a legend of the row colors below the table
+    #[arg(long)]
+    pub legend: bool,
+
+    /// Print only the requested data and errors, without hints or warnings
+    #[arg(short, long)]
+    pub quiet: bool,
+
+    /// Table border style [default: borders-only]
+    #[arg(long, value_name = "STYLE", value_enum)]
+    pub border: Option<Border>,
+
+    /// Table color preset [default: default]; --color never turns colors off
+    /// regardless
+    #[arg(long, value_name = "THEME", value_enum)]
+    pub theme: Option<ThemeName>,
+
+    /// When to use colors
+    #[arg(
+        long,
+        value_name = "WHEN",
+        value_enum,
+        default_value_t,
+        conflicts_with = "no_color"
+    )]
+    pub color: ColorWhen,
+
+    /// Disable colors, like --color never (also honours NO_COLOR)
+    #[arg(long)]
+    pub no_color: bool,
+
+    /// Print a completion script for SHELL
+    #[arg(long, value_name = "SHELL")]
+    pub completions: Option<Shell>,
+}
+
+impl Cli {
+    /// The format selected by the format flags, if any.
+    pub fn format(&self) -> Option<Format> {
+        if self.output_format.is_some() {
+            self.output_format
+        } else if self.json || self.raw_json {
+            Some(Format::Json)
+        } else if self.minimal_json {
+            Some(Format::MinimalJson)
+        } else if self.jsonl {
+            Some(Format::Jsonl)
+        } else if self.csv {
+            Some(Format::Csv)
+        } else if self.tsv {
+            Some(Format::Tsv)
+        } else if self.yaml {
+            Some(Format::Yaml)
+        } else if self.markdown {
+            Some(Format::Markdown)
+        } else if self.html {
+            Some(Format::Html)
+        } else if self.xml {
+            Some(Format::Xml)
+        } else if self.toml {
+            Some(Format::Toml)
+        } else if self.plain {
+            Some(Format::Plain)
+        } else if self.only_codes {
+            Some(Format::OnlyCodes)
+        } else if self.force_table {
+            Some(Format::Table)
+        } else if self.json_objects {
+            Some(Format::Json)
+        } else {
+            #[cfg(feature = "external-jq")]
+            if self.jq {
+                return Some(Format::Json);
+            }
+            None
+        }
+    }
+
+    /// Whether a table-only option was given: `--columns`, `--border`,
+    /// `--legend`, `--emoji`, `--theme` or `--color always`. These keep the
+    /// table when output is not a terminal.
+    pub fn wants_table(&self) -> bool {
+        !self.columns.is_empty()
+            || self.border.is_some()
+            || self.legend
+            || self.emoji
+            || self.theme.is_some()
+            || self.color_when() == ColorWhen::Always
+    }
+
+    /// When to use colors: `--color`, with `--no-color` as a shorthand for
+    /// `never`.
+    pub fn color_when(&self) -> ColorWhen {
+        if self.no_color {
+            ColorWhen::Never
+        } else {
+            self.color
+        }
+    }
+
+    /// Whether codes are marked IANA or unofficial: with `--extended`, and
+    /// with `--codes-file`, whose codes are unofficial.
+    pub fn shows_source(&self) -> bool {
+        self.extended || self.codes_file.is_some()
+    }
+
+    /// The table columns: `--columns` if given, otherwise code and
+    /// description, plus the RFC column when `--rfc` is set and the source
+    /// column when `--extended` or `--codes-file` is set.
+    pub fn columns(&self) -> Vec<Column> {
+        let mut columns = if self.columns.is_empty() {
+            vec![Column::Code, Column::Description]
+        } else {
+            self.columns.clone()
+        };
+        if self.rfc && !columns.contains(&Column::Rfc) {
+            columns.push(Column::Rfc);
+        }
+        if self.shows_source() && !columns.contains(&Column::Source) {
+            columns.push(Column::Source);
+        }
+        columns
+    }
+}
+
+/// Parses a status code, or an informal name for one such as `teapot`.
+fn parse_code(value: &str) -> Result<u16, String> {
+    value
+        .parse()
+        .ok()
+        .or_else(|| alias_code(value))
+        .ok_or_else(|| "expected a status code or a known name such as 'teapot'".to_string())
+}
+
+/// Parses a code or name like [`parse_code`], or a `start-end` range.
+fn parse_lookup(value: &str) -> Result<Lookup, String> {
+    let range = value
+        .split_once('-')
+        .and_then(|(start, end)| Some((start.parse().ok()?, end.parse().ok()?)));
+    match range {
+        Some((start, end)) if start <= end => Ok(Lookup::Range(start, end)),
+        Some((start, end)) => Err(format!("range {}-{} ends before it starts", start, end)),
+        None => parse_code(value).map(Lookup::Code),
+    }
+}
+
+/// Parses a status class such as `4xx`, or its name such as `client error`,
+/// into its hundreds digit. Names ignore case and accept hyphens or
+/// underscores between words.
+fn parse_class(value: &str) -> Result<u16, String> {
+    let value = value.trim().to_ascii_lowercase();
+    let name = value.replace(['-', '_'], " ");
+    value
+        .strip_suffix("xx")
+        .and_then(|digit| digit.parse().ok())
+        .or_else(|| {
+            (1..=5).find(|&digit| {
+                class_name(digit * 100).is_some_and(|class| class.to_ascii_lowercase() == name)
+            })
+        })
+        .filter(|digit| (1..=5).contains(digit))
+        .ok_or_else(|| {
+            "expected one of 1xx, 2xx, 3xx, 4xx, 5xx, informational, success, redirection, \
+             client error, server error"
+                .to_string()
+        })
+}
+
+pub fn print_completions(shell: Shell) {
+    clap_complete::generate(shell, &mut Cli::command(), "httpstatus", &mut io::stdout());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cli, ColorWhen, Column, Format, Lookup, parse_class, parse_code, parse_lookup};
+    use clap::{CommandFactory, Parser};
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_version_includes_package_version() {
+        let version = Cli::command().render_version();
+        assert!(version.starts_with(concat!("httpstatus ", env!("CARGO_PKG_VERSION"))));
+    }
+
+    #[test]
+    fn test_rejects_unknown_flags() {
+        assert!(Cli::try_parse_from(["httpstatus", "--bogus"]).is_err());
+        assert!(Cli::try_parse_from(["httpstatus", "not-a-code"]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_multiple_formats() {
+        assert!(Cli::try_parse_from(["httpstatus", "--json", "--csv"]).is_err());
+        assert!(Cli::try_parse_from(["httpstatus", "--yaml", "--markdown"]).is_err());
+    }
+
+    #[test]
+    fn test_parses_code_with_format() {
+        let cli = Cli::try_parse_from(["httpstatus", "404", "--csv"]).unwrap();
+        assert_eq!(cli.codes, [Lookup::Code(404)]);
+        assert_eq!(cli.format(), Some(Format::Csv));
+    }
+
+    #[test]
+    fn test_flag_order_does_not_matter() {
+        for args in [
+            ["httpstatus", "--json", "404"],
+            ["httpstatus", "404", "--json"],
+            ["httpstatus", "-j", "404"],
+            ["httpstatus", "404", "-j"],
+        ] {
+            let cli = Cli::try_parse_from(args).unwrap();
+            assert_eq!(cli.codes, [Lookup::Code(404)], "{:?}", args);
+            assert_eq!(cli.format(), Some(Format::Json), "{:?}", args);
+        }
+
+        let cli = Cli::try_parse_from(["httpstatus", "200", "--csv", "404"]).unwrap();
+        assert_eq!(cli.codes, [Lookup::Code(200), Lookup::Code(404)]);
+        assert_eq!(cli.format(), Some(Format::Csv));
+    }
+
+    #[test]
+    fn test_format_flag_overrides_default() {
+        let cli = Cli::try_parse_from(["httpstatus"]).unwrap();
+        assert_eq!(cli.format(), None);
+
+        let cli = Cli::try_parse_from(["httpstatus", "--csv"]).unwrap();
+        assert_eq!(cli.format(), Some(Format::Csv));
+    }
+
+    #[test]
+    fn test_json_pretty_and_compact() {
+        let cli = Cli::try_parse_from(["httpstatus", "--json-pretty"]).unwrap();
+        assert!(cli.raw_json);
+        assert_eq!(cli.format(), Some(Format::Json));
+        let cli = Cli::try_parse_from(["httpstatus", "--json-compact"]).unwrap();
+        assert_eq!(cli.format(), Some(Format::MinimalJson));
+        assert!(Cli::try_parse_from(["httpstatus", "--json-pretty", "--json-compact"]).is_err());
+    }
+
+    #[test]
+    fn test_invert_needs_a_filter() {
+        assert!(Cli::try_parse_from(["httpstatus", "--invert"]).is_err());
+        for filter in [["--class", "2xx"], ["--search", "gateway"]] {
+            let mut args = vec!["httpstatus", "--invert"];
+            args.extend(filter);
+            assert!(Cli::try_parse_from(args).unwrap().invert);
+        }
+    }
+
+    #[test]
+    fn test_force_table() {
+        let cli = Cli::try_parse_from(["httpstatus", "--force-table"]).unwrap();
+        assert_eq!(cli.format(), Some(Format::Table));
+        assert!(Cli::try_parse_from(["httpstatus", "--force-table", "--plain"]).is_err());
+    }
+
+    #[test]
+    fn test_format_option() {
+        let cli = Cli::try_parse_from(["httpstatus", "--format", "yaml"]).unwrap();
+        assert_eq!(cli.format(), Some(Format::Yaml));
+
+        let cli = Cli::try_parse_from(["httpstatus", "--format", "only-codes"]).unwrap();
+        assert_eq!(cli.format(), Some(Format::OnlyCodes));
+
+        assert!(Cli::try_parse_from(["httpstatus", "--format", "json", "--csv"]).is_err());
+        assert!(Cli::try_parse_from(["httpstatus", "--format", "bogus"]).is_err());
+    }
+
+    #[test]
+    fn test_parses_multiple_codes() {
+        let cli = Cli::try_parse_from(["httpstatus", "200", "404", "500"]).unwrap();
+        assert_eq!(
+            cli.codes,
+            [Lookup::Code(200), Lookup::Code(404), Lookup::Code(500)]
+        );
+    }
+
+    #[test]
+    fn test_wants_table() {
+        let cli = Cli::try_parse_from(["httpstatus", "--class", "4xx"]).unwrap();
+        assert!(!cli.wants_table());
+        for flag in [
+            &["--columns", "code"][..],
+            &["--border", "ascii"],
+            &["--legend"],
+            &["--emoji"],
+            &["--theme", "mono"],
+            &["--color", "always"],
+        ] {
+            let cli = Cli::try_parse_from(["httpstatus"].iter().chain(flag)).unwrap();
+            assert!(cli.wants_table(), "{:?}", flag);
+        }
+    }
+
+    #[test]
+    fn test_columns() {
+        let cli = Cli::try_parse_from(["httpstatus"]).unwrap();
+        assert_eq!(cli.columns(), [Column::Code, Column::Description]);
+
+        let cli = Cli::try_parse_from(["httpstatus", "--rfc"]).unwrap();
+        assert_eq!(
+            cli.columns(),
+            [Column::Code, Column::Description, Column::Rfc]
+        );
+
+        let cli = Cli::try_parse_from(["httpstatus", "--extended"]).unwrap();
+        assert_eq!(
+            cli.columns(),
+            [Column::Code, Column::Description, Column::Source]
+        );
+
+        let cli = Cli::try_parse_from(["httpstatus", "--codes-file", "c.json"]).unwrap();
+        assert_eq!(
+            cli.columns(),
+            [Column::Code, Column::Description, Column::Source]
+        );
+
+        let cli = Cli::try_parse_from(["httpstatus", "--columns", "rfc,code"]).unwrap();
+        assert_eq!(cli.columns(), [Column::Rfc, Column::Code]);
+
+        assert!(Cli::try_parse_from(["httpstatus", "--columns", "code,bogus"]).is_err());
+    }
+
+    #[test]
+    fn test_wrap() {
+        let cli = Cli::try_parse_from(["httpstatus", "--wrap", "60"]).unwrap();
+        assert_eq!(cli.wrap, Some(60));
+        assert!(Cli::try_parse_from(["httpstatus", "--wrap", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_extended_conflicts_with_no_extended() {
+        assert!(Cli::try_parse_from(["httpstatus", "--extended", "--no-extended"]).is_err());
+        assert!(
+            Cli::try_parse_from(["httpstatus", "--no-extended"])
+                .unwrap()
+                .no_extended
+        );
+    }
+
+    #[test]
+    fn test_color_when() {
+        let cli = Cli::try_parse_from(["httpstatus"]).unwrap();
+        assert_eq!(cli.color_when(), ColorWhen::Auto);
+        let cli = Cli::try_parse_from(["httpstatus", "--color", "never"]).unwrap();
+        assert_eq!(cli.color_when(), ColorWhen::Never);
+        let cli = Cli::try_parse_from(["httpstatus", "--no-color"]).unwrap();
+        assert_eq!(cli.color_when(), ColorWhen::Never);
+        assert!(Cli::try_parse_from(["httpstatus", "--no-color", "--color", "always"]).is_err());
+    }
+
+    #[test]
+    fn test_json_objects_implies_json() {
+        let cli = Cli::try_parse_from(["httpstatus", "--json-objects"]).unwrap();
+        assert_eq!(cli.format(), Some(Format::Json));
+        let cli = Cli::try_parse_from(["httpstatus", "--json-objects", "--minimal-json"]).unwrap();
+        assert_eq!(cli.format(), Some(Format::MinimalJson));
+    }
+
+    #[cfg(feature = "external-jq")]
+    #[test]
+    fn test_jq_implies_json() {
+        let cli = Cli::try_parse_from(["httpstatus", "--jq"]).unwrap();
+        assert_eq!(cli.format(), Some(Format::Json));
+        assert!(!Cli::try_parse_from(["httpstatus", "--json"]).unwrap().jq);
+    }
+
+    #[test]
+    fn test_first_and_last_conflict() {
+        let cli = Cli::try_parse_from(["httpstatus", "--first", "5"]).unwrap();
+        assert_eq!(cli.first, Some(5));
+        assert!(Cli::try_parse_from(["httpstatus", "--first", "5", "--last", "5"]).is_err());
+        assert!(Cli::try_parse_from(["httpstatus", "--last", "-1"]).is_err());
+    }
+
+    #[test]
+    fn test_quiet() {
+        assert!(
+            Cli::try_parse_from(["httpstatus", "-q", "404"])
+                .unwrap()
+                .quiet
+        );
+        assert!(!Cli::try_parse_from(["httpstatus", "404"]).unwrap().quiet);
+    }
+
+    #[test]
+    fn test_parse_class() {
+        assert_eq!(parse_class("1xx"), Ok(1));
+        assert_eq!(parse_class("4xx"), Ok(4));
+        assert_eq!(parse_class("5XX"), Ok(5));
+        assert_eq!(parse_class("success"), Ok(2));
+        assert_eq!(parse_class("Client Error"), Ok(4));
+        assert_eq!(parse_class("server-error"), Ok(5));
+        assert!(parse_class("error").is_err());
+        assert!(parse_class("6xx").is_err());
+        assert!(parse_class("0xx").is_err());
+        assert!(parse_class("4").is_err());
+        assert!(parse_class("").is_err());
+    }
+
+    #[test]
+    fn test_parse_code() {
+        assert_eq!(parse_code("404"), Ok(404));
+        assert_eq!(parse_code("teapot"), Ok(418));
+        assert_eq!(parse_code("rate limit"), Ok(429));
+        assert!(parse_code("banana").is_err());
+
+        let cli = Cli::try_parse_from(["httpstatus", "--explain", "teapot"]).unwrap();
+        assert_eq!(cli.explain, Some(418));
+    }
+
+    #[test]
+    fn test_output_file() {
+        let cli = Cli::try_parse_from(["httpstatus", "--csv", "-o", "codes.csv"]).unwrap();
+        assert_eq!(cli.output.as_deref(), Some("codes.csv".as_ref()));
+        assert!(Cli::try_parse_from(["httpstatus", "-i", "--output", "log.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_lookup() {
+        assert_eq!(parse_lookup("404"), Ok(Lookup::Code(404)));
+        assert_eq!(parse_lookup("400-410"), Ok(Lookup::Range(400, 410)));
+        assert_eq!(parse_lookup("rate-limit"), Ok(Lookup::Code(429)));
+        assert!(parse_lookup("410-400").is_err());
+        assert!(parse_lookup("400-").is_err());
+    }
+}
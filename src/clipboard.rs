@@ -0,0 +1,15 @@
+//! Copying a lookup to the system clipboard for `--copy`. Needs the
+//! `clipboard` cargo feature.
+
+/// Puts `text` on the clipboard, or explains why it could not.
+#[cfg(feature = "clipboard")]
+pub fn copy(text: &str) -> Result<(), String> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(text))
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(not(feature = "clipboard"))]
+pub fn copy(_text: &str) -> Result<(), String> {
+    Err("built without the clipboard feature".to_string())
+}
@@ -0,0 +1,480 @@
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+/// A registered HTTP status code and the specification that defines it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Status {
+    pub code: u16,
+    pub description: &'static str,
+    /// The defining RFC, or empty for unofficial codes.
+    pub rfc: &'static str,
+    /// When the status is used and what clients should do about it.
+    pub explanation: &'static str,
+    /// The request methods the status is usually a response to, such as PUT
+    /// for 412 Precondition Failed. Empty where no method stands out.
+    pub related_methods: &'static [&'static str],
+    /// How the status behaves differently across HTTP/1.1, HTTP/2 and HTTP/3,
+    /// such as 421 Misdirected Request on coalesced connections. Empty where
+    /// the version makes no difference.
+    pub protocol_notes: &'static str,
+    /// A sample request and response, for common codes only. Empty otherwise.
+    pub example: &'static str,
+    /// Sample values of the headers that matter most for the status, such as
+    /// `Retry-After` in both its seconds and HTTP-date forms for 503.
+    pub header_examples: &'static [&'static str],
+    /// Whether retrying the same request later may succeed, as for 429 Too
+    /// Many Requests and 503 Service Unavailable.
+    pub retryable: bool,
+    /// How to retry a retryable status, such as honouring `Retry-After`.
+    /// Empty for the others.
+    pub retry_advice: &'static str,
+    /// Obsolete or reserved codes, such as 305 Use Proxy, that new code should
+    /// not rely on.
+    pub deprecated: bool,
+    /// Whether the code is registered with IANA or vendor-specific.
+    pub source: Source,
+}
+
+/// Where a status code comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Source {
+    /// Registered with IANA.
+    Iana,
+    /// Vendor-specific, as returned by [`unofficial_status_codes`].
+    Unofficial,
+}
+
+impl Source {
+    /// `iana` or `unofficial`, as shown in the output formats.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Source::Iana => "iana",
+            Source::Unofficial => "unofficial",
+        }
+    }
+}
+
+/// The fallback used by [`description_or_unknown`].
+pub const UNKNOWN_DESCRIPTION: &str = "Unknown Status Code";
+
+/// The registered codes and their reason phrases as a slice sorted by code,
+/// for lookups that need neither allocation nor the map. See
+/// [`lookup_slice`].
+pub const SORTED_CODES: &[(u16, &str)] = SORTED_PAIRS;
+
+/// The number of IANA-registered codes in [`status_codes`].
+pub const STATUS_CODE_COUNT: usize = STATUSES.len();
+
+// `STATUSES` and `UNOFFICIAL_STATUSES`, the widely seen codes that are not
+// registered with IANA, are generated by `build.rs` from `data/codes.json`.
+include!(concat!(env!("OUT_DIR"), "/codes.rs"));
+
+static STATUS_CODES: LazyLock<BTreeMap<u16, Status>> = LazyLock::new(|| {
+    STATUSES
+        .iter()
+        .map(|status| (status.code, *status))
+        .collect()
+});
+
+static UNOFFICIAL_STATUS_CODES: LazyLock<BTreeMap<u16, Status>> = LazyLock::new(|| {
+    UNOFFICIAL_STATUSES
+        .iter()
+        .map(|status| (status.code, *status))
+        .collect()
+});
+
+/// Returns every IANA-registered status code mapped to its record.
+///
+/// The map is built on first use and shared afterwards, so repeated lookups
+/// are cheap.
+pub fn status_codes() -> &'static BTreeMap<u16, Status> {
+    &STATUS_CODES
+}
+
+/// Returns the unofficial, vendor-specific status codes mapped to their
+/// records. None of these overlap with [`status_codes`].
+pub fn unofficial_status_codes() -> &'static BTreeMap<u16, Status> {
+    &UNOFFICIAL_STATUS_CODES
+}
+
+/// Returns true if `code` is an IANA-registered status code.
+///
+/// ```
+/// assert!(httpstatus_rust::contains(404));
+/// assert!(!httpstatus_rust::contains(520));
+/// ```
+pub fn contains(code: u16) -> bool {
+    status_codes().contains_key(&code)
+}
+
+/// Returns true if retrying a request that got `code` may succeed, such as
+/// after 429 Too Many Requests or 503 Service Unavailable. False for codes
+/// that are not registered.
+///
+/// ```
+/// assert!(httpstatus_rust::is_retryable(503));
+/// assert!(!httpstatus_rust::is_retryable(404));
+/// ```
+pub fn is_retryable(code: u16) -> bool {
+    status_codes()
+        .get(&code)
+        .is_some_and(|status| status.retryable)
+}
+
+/// Iterates over the registered codes and their reason phrases in ascending
+/// order, without cloning the map.
+///
+/// ```
+/// let client_errors = httpstatus_rust::iter().filter(|&(code, _)| code / 100 == 4);
+/// assert_eq!(client_errors.count(), 29);
+/// ```
+pub fn iter() -> impl Iterator<Item = (u16, &'static str)> {
+    status_codes()
+        .iter()
+        .map(|(&code, status)| (code, status.description))
+}
+
+/// Whether `description` contains `term`, ignoring case, the matching rule
+/// of [`search`].
+pub(crate) fn matches(description: &str, term: &str) -> bool {
+    description.to_lowercase().contains(&term.to_lowercase())
+}
+
+/// Finds the registered codes whose reason phrase contains `term`, ignoring
+/// case, the way `httpstatus --search` does. An empty term is contained in
+/// every phrase, so it returns all codes.
+///
+/// ```
+/// let matches = httpstatus_rust::search("GATEWAY");
+/// assert_eq!(matches, [(502, "Bad Gateway"), (504, "Gateway Timeout")]);
+/// ```
+pub fn search(term: &str) -> Vec<(u16, &'static str)> {
+    iter()
+        .filter(|(_, description)| matches(description, term))
+        .collect()
+}
+
+/// Looks up the reason phrase for a registered code by binary search over
+/// [`SORTED_CODES`], without building the map.
+///
+/// ```
+/// assert_eq!(httpstatus_rust::lookup_slice(418), Some("I'm a teapot"));
+/// assert_eq!(httpstatus_rust::lookup_slice(520), None);
+/// ```
+pub fn lookup_slice(code: u16) -> Option<&'static str> {
+    SORTED_CODES
+        .binary_search_by_key(&code, |&(code, _)| code)
+        .ok()
+        .map(|index| SORTED_CODES[index].1)
+}
+
+/// Looks up the reason phrase for a single status code.
+pub fn description(code: u16) -> Option<&'static str> {
+    status_codes().get(&code).map(|status| status.description)
+}
+
+/// Looks up the reason phrase for a code, falling back to `default` for codes
+/// that are not registered.
+///
+/// ```
+/// assert_eq!(httpstatus_rust::description_or(520, "Origin Error"), "Origin Error");
+/// ```
+pub fn description_or(code: u16, default: &'static str) -> &'static str {
+    description(code).unwrap_or(default)
+}
+
+/// Looks up the reason phrase for a code, falling back to
+/// [`UNKNOWN_DESCRIPTION`] for codes that are not registered.
+pub fn description_or_unknown(code: u16) -> &'static str {
+    description_or(code, UNKNOWN_DESCRIPTION)
+}
+
+/// Owned variant of [`description`], for APIs that need a `String`.
+///
+/// ```
+/// let reason: Option<String> = httpstatus_rust::reason_phrase(429);
+/// assert_eq!(reason.as_deref(), Some("Too Many Requests"));
+/// ```
+pub fn reason_phrase(code: u16) -> Option<String> {
+    description(code).map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        SORTED_CODES, STATUS_CODE_COUNT, Source, Status, UNKNOWN_DESCRIPTION, contains,
+        description, description_or, description_or_unknown, is_retryable, iter, lookup_slice,
+        matches, reason_phrase, search, status_codes, unofficial_status_codes,
+    };
+    use crate::validate::{check_ascending, check_class_counts, check_descriptions};
+
+    fn all_statuses() -> Vec<Status> {
+        status_codes().values().copied().collect()
+    }
+
+    #[test]
+    fn test_status_codes_count() {
+        let status_codes = status_codes();
+        assert_eq!(
+            status_codes.len(),
+            STATUS_CODE_COUNT,
+            "Every record should have a distinct code"
+        );
+    }
+
+    #[test]
+    fn test_status_codes_order() {
+        assert_eq!(check_ascending(&all_statuses()), Ok(()));
+    }
+
+    #[test]
+    fn test_common_status_codes() {
+        let status_codes = status_codes();
+
+        let description = |code| status_codes.get(&code).map(|status| status.description);
+
+        assert_eq!(description(200), Some("OK"));
+        assert_eq!(description(302), Some("Found"));
+        assert_eq!(description(404), Some("Not Found"));
+        assert_eq!(description(418), Some("I'm a teapot"));
+    }
+
+    #[test]
+    fn test_status_code_ranges() {
+        assert_eq!(check_class_counts(&all_statuses()), Ok(()));
+    }
+
+    #[test]
+    fn test_non_existent_status_code() {
+        let status_codes = status_codes();
+        assert!(!status_codes.contains_key(&999));
+        assert!(!status_codes.contains_key(&666));
+    }
+
+    #[test]
+    fn test_all_values_are_non_empty() {
+        assert_eq!(check_descriptions(&all_statuses()), Ok(()));
+    }
+
+    #[test]
+    fn test_description_or() {
+        assert_eq!(description_or(404, "Fallback"), "Not Found");
+        assert_eq!(description_or(520, "Fallback"), "Fallback");
+    }
+
+    #[test]
+    fn test_description_or_unknown() {
+        assert_eq!(description_or_unknown(200), "OK");
+        assert_eq!(description_or_unknown(520), "Unknown Status Code");
+        assert_eq!(description_or_unknown(999), UNKNOWN_DESCRIPTION);
+    }
+
+    #[test]
+    fn test_status_codes_are_built_once() {
+        assert!(std::ptr::eq(status_codes(), status_codes()));
+    }
+
+    #[test]
+    fn test_deprecated_codes() {
+        let deprecated: Vec<u16> = status_codes()
+            .values()
+            .filter(|status| status.deprecated)
+            .map(|status| status.code)
+            .collect();
+        assert_eq!(deprecated, [305, 306]);
+    }
+
+    #[test]
+    fn test_contains_and_iter() {
+        assert!(contains(200));
+        assert!(!contains(999));
+        assert_eq!(iter().count(), STATUS_CODE_COUNT);
+        assert_eq!(iter().next(), Some((100, "Continue")));
+        assert!(iter().all(|(code, _)| contains(code)));
+    }
+
+    #[test]
+    fn test_search() {
+        assert_eq!(search("teapot"), [(418, "I'm a teapot")]);
+        assert_eq!(search("Too Many"), [(429, "Too Many Requests")]);
+        assert!(search("banana").is_empty());
+        assert_eq!(search("").len(), STATUS_CODE_COUNT);
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(matches("Bad Gateway", "GATEWAY"));
+        assert!(matches("Zeitüberschreitung", "ÜBER"));
+        assert!(!matches("Bad Gateway", "timeout"));
+    }
+
+    #[test]
+    fn test_description() {
+        assert_eq!(description(200), Some("OK"));
+        assert_eq!(description(503), Some("Service Unavailable"));
+        assert_eq!(description(999), None);
+    }
+
+    #[test]
+    fn test_reason_phrase() {
+        assert_eq!(reason_phrase(404), Some(String::from("Not Found")));
+        assert_eq!(reason_phrase(999), None);
+    }
+
+    #[test]
+    fn test_records_are_consistent() {
+        for (&code, status) in status_codes() {
+            assert_eq!(code, status.code, "Key and record disagree for {}", code);
+            assert!(
+                status.rfc.starts_with("RFC "),
+                "Code {} has no RFC reference",
+                code
+            );
+            assert!(
+                !status.explanation.is_empty(),
+                "Code {} has no explanation",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn test_rfc_references() {
+        let status_codes = status_codes();
+        assert_eq!(status_codes[&200].rfc, "RFC 9110");
+        assert_eq!(status_codes[&425].rfc, "RFC 8470");
+        assert_eq!(status_codes[&429].rfc, "RFC 6585");
+    }
+
+    #[test]
+    fn test_unofficial_status_codes() {
+        let official = status_codes();
+        let unofficial = unofficial_status_codes();
+
+        assert_eq!(
+            unofficial.get(&499).map(|s| s.description),
+            Some("Client Closed Request")
+        );
+        assert_eq!(unofficial.get(&520).map(|s| s.code), Some(520));
+        for (code, status) in unofficial {
+            assert!(!official.contains_key(code), "Code {} is official", code);
+            assert!(status.rfc.is_empty(), "Code {} has an RFC", code);
+            assert!(!status.explanation.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_early_hints_and_too_early_explanations() {
+        let early_hints = status_codes()[&103].explanation;
+        assert!(early_hints.contains("`Link`"));
+        assert!(early_hints.contains("cache"));
+        let too_early = status_codes()[&425].explanation;
+        assert!(too_early.contains("replayed"));
+        assert!(too_early.contains("`Early-Data: 1`"));
+    }
+
+    #[test]
+    fn test_sources() {
+        assert!(status_codes().values().all(|s| s.source == Source::Iana));
+        assert!(
+            unofficial_status_codes()
+                .values()
+                .all(|s| s.source == Source::Unofficial)
+        );
+        assert_eq!(Source::Unofficial.as_str(), "unofficial");
+    }
+
+    #[test]
+    fn test_related_methods() {
+        assert_eq!(
+            status_codes()[&411].related_methods,
+            ["POST", "PUT", "PATCH"]
+        );
+        assert!(status_codes()[&404].related_methods.is_empty());
+        for status in status_codes().values() {
+            for method in status.related_methods {
+                assert!(
+                    method.chars().all(|c| c.is_ascii_uppercase()),
+                    "Code {} has a malformed method '{}'",
+                    status.code,
+                    method
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_examples_show_their_own_status_line() {
+        for status in status_codes().values() {
+            if status.example.is_empty() {
+                continue;
+            }
+            let status_line = format!("\n\nHTTP/1.1 {} {}", status.code, status.description);
+            assert!(
+                status.example.contains(&status_line),
+                "The example of {} should respond with '{}'",
+                status.code,
+                status_line.trim()
+            );
+        }
+        assert!(!status_codes()[&404].example.is_empty());
+        assert!(status_codes()[&418].example.is_empty());
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        let retryable: Vec<u16> = status_codes()
+            .keys()
+            .copied()
+            .filter(|&code| is_retryable(code))
+            .collect();
+        assert_eq!(retryable, [408, 425, 429, 500, 502, 503, 504]);
+        assert!(!is_retryable(520));
+        assert!(status_codes()[&429].retry_advice.contains("Retry-After"));
+    }
+
+    #[test]
+    fn test_sorted_codes_match_the_map() {
+        assert!(SORTED_CODES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(SORTED_CODES.len(), STATUS_CODE_COUNT);
+        assert!(SORTED_CODES.iter().copied().eq(iter()));
+        for code in 0..=1000 {
+            assert_eq!(lookup_slice(code), description(code), "Code {}", code);
+        }
+    }
+
+    #[test]
+    fn test_retry_after_examples() {
+        for code in [429, 503] {
+            let examples = status_codes()[&code].header_examples;
+            assert!(
+                examples
+                    .iter()
+                    .all(|header| header.starts_with("Retry-After: "))
+            );
+            assert!(
+                examples
+                    .iter()
+                    .any(|header| header[13..].parse::<u32>().is_ok())
+            );
+            assert!(examples.iter().any(|header| header.ends_with(" GMT")));
+        }
+        assert!(status_codes()[&404].header_examples.is_empty());
+    }
+
+    #[test]
+    fn test_protocol_notes() {
+        let noted: Vec<u16> = status_codes()
+            .values()
+            .filter(|status| !status.protocol_notes.is_empty())
+            .map(|status| status.code)
+            .collect();
+        assert_eq!(noted, [101, 103, 421, 425, 426, 505]);
+        assert!(
+            status_codes()
+                .values()
+                .all(|status| status.protocol_notes.is_empty()
+                    || status.protocol_notes.contains("HTTP/"))
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! Persistent defaults read from `~/.config/httpstatus/config.toml`, or from
+//! `$XDG_CONFIG_HOME/httpstatus/config.toml` when that is set. Flags on the
+//! command line always win.
+
+use crate::cli::{Format, ThemeName};
+use log::debug;
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Used when neither a format flag nor `HTTPSTATUS_FORMAT` is given.
+    pub format: Option<Format>,
+    pub theme: Option<ThemeName>,
+    /// Include unofficial codes unless `--no-extended` is passed.
+    pub extended: Option<bool>,
+}
+
+/// Where the config file is looked for, if a home directory is known.
+pub fn path() -> Option<PathBuf> {
+    let base = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+    Some(base.join("httpstatus").join("config.toml"))
+}
+
+fn parse(text: &str) -> Result<Config, toml::de::Error> {
+    toml::from_str(text)
+}
+
+/// Reads the config file. A missing file gives the empty config; a file that
+/// cannot be read or parsed is an error naming the path.
+pub fn load() -> Result<Config, String> {
+    let Some(path) = path() else {
+        return Ok(Config::default());
+    };
+    match fs::read_to_string(&path) {
+        Ok(text) => {
+            debug!("Reading config from {}", path.display());
+            parse(&text).map_err(|e| format!("{}: {}", path.display(), e))
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            debug!("No config file at {}", path.display());
+            Ok(Config::default())
+        }
+        Err(e) => Err(format!("{}: {}", path.display(), e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, parse};
+    use crate::cli::{Format, ThemeName};
+
+    #[test]
+    fn test_parse() {
+        let config = parse("format = \"json\"\ntheme = \"mono\"\nextended = true\n").unwrap();
+        assert_eq!(
+            config,
+            Config {
+                format: Some(Format::Json),
+                theme: Some(ThemeName::Mono),
+                extended: Some(true),
+            }
+        );
+        assert_eq!(parse("").unwrap(), Config::default());
+        assert_eq!(
+            parse("format = \"only-codes\"").unwrap().format,
+            Some(Format::OnlyCodes)
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_settings() {
+        assert!(parse("format = \"bogus\"").is_err());
+        assert!(parse("colour = \"red\"").is_err());
+    }
+}
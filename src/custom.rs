@@ -0,0 +1,123 @@
+//! User-defined codes from `--codes-file`, such as internal 6xx codes of a
+//! proprietary API. The file maps codes to descriptions like `--json` output:
+//! `{"600": "Quota Exceeded"}`.
+
+use httpstatus_rust::{Source, Status};
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::fs;
+use std::path::Path;
+
+/// Parses the file contents into statuses, in ascending order. Codes must be
+/// three-digit numbers, and descriptions must not be empty or contain control
+/// characters such as tabs and line breaks, which would split TSV and plain
+/// rows.
+pub fn parse(text: &str) -> Result<Vec<Status>, String> {
+    let entries: BTreeMap<String, String> =
+        serde_json::from_str(text).map_err(|e| e.to_string())?;
+    let mut statuses: Vec<Status> = entries
+        .into_iter()
+        .map(|(key, description)| {
+            let code = key
+                .trim()
+                .parse()
+                .ok()
+                .filter(|code| (100..=999).contains(code))
+                .ok_or_else(|| format!("'{}' is not a three-digit status code", key))?;
+            if description.trim().is_empty() {
+                return Err(format!("code {} has an empty description", code));
+            }
+            if description.contains(char::is_control) {
+                return Err(format!(
+                    "code {} has a tab, line break or other control character in its description",
+                    code
+                ));
+            }
+            Ok(Status {
+                code,
+                // The strings live for the rest of the run, like the built-in
+                // tables they are merged into.
+                description: String::leak(description),
+                rfc: "",
+                explanation: "",
+                related_methods: &[],
+                protocol_notes: "",
+                example: "",
+                header_examples: &[],
+                retryable: false,
+                retry_advice: "",
+                deprecated: false,
+                source: Source::Unofficial,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    statuses.sort_by_key(|status| status.code);
+    Ok(statuses)
+}
+
+/// Adds `custom` to `codes`. An entry for a known code only replaces its
+/// description, keeping the RFC, explanation and IANA source, so `--selfcheck`
+/// still counts it. Returns each overridden status with its replacement.
+pub fn merge(codes: &mut BTreeMap<u16, Status>, custom: Vec<Status>) -> Vec<(Status, Status)> {
+    let mut overrides = Vec::new();
+    for status in custom {
+        match codes.entry(status.code) {
+            Entry::Occupied(mut entry) => {
+                overrides.push((*entry.get(), status));
+                entry.get_mut().description = status.description;
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(status);
+            }
+        }
+    }
+    overrides
+}
+
+/// Reads and parses a codes file, with errors naming the path.
+pub fn load(path: &Path) -> Result<Vec<Status>, String> {
+    fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| parse(&text))
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{merge, parse};
+    use httpstatus_rust::{Source, status_codes};
+
+    #[test]
+    fn test_parse() {
+        let statuses = parse(r#"{"601": "Over Budget", "600": "Quota Exceeded"}"#).unwrap();
+        let codes: Vec<u16> = statuses.iter().map(|status| status.code).collect();
+        assert_eq!(codes, [600, 601]);
+        assert_eq!(statuses[0].description, "Quota Exceeded");
+        assert_eq!(statuses[0].source, Source::Unofficial);
+        assert!(parse("{}").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut codes = status_codes().clone();
+        let custom = parse(r#"{"404": "Gone Fishing", "600": "Quota Exceeded"}"#).unwrap();
+        let overrides = merge(&mut codes, custom);
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides[0].0.description, "Not Found");
+        assert_eq!(codes[&404].description, "Gone Fishing");
+        assert_eq!(codes[&404].rfc, status_codes()[&404].rfc);
+        assert_eq!(codes[&404].source, Source::Iana);
+        assert_eq!(codes[&600].source, Source::Unofficial);
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_entries() {
+        assert!(parse(r#"{"6000": "Too Long"}"#).is_err());
+        assert!(parse(r#"{"abc": "Not A Code"}"#).is_err());
+        assert!(parse(r#"{"600": " "}"#).is_err());
+        assert!(parse(r#"{"600": "Quota\tExceeded"}"#).is_err());
+        assert!(parse(r#"{"600": "Quota\nExceeded"}"#).is_err());
+        assert!(parse(r#"{"600": 1}"#).is_err());
+        assert!(parse("[]").is_err());
+    }
+}
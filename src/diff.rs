@@ -0,0 +1,94 @@
+use crate::output;
+use httpstatus_rust::Status;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, Write};
+
+/// How a list of codes differs from the known set.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Diff {
+    /// Known codes that are not in the list.
+    pub missing: Vec<Status>,
+    /// Listed codes that are not known.
+    pub unknown: Vec<u16>,
+}
+
+impl Diff {
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.unknown.is_empty()
+    }
+}
+
+/// Parses whitespace-separated codes, reporting the first token that is not
+/// a code.
+pub fn parse_codes(text: &str) -> Result<BTreeSet<u16>, String> {
+    text.split_whitespace()
+        .map(|token| {
+            token
+                .parse()
+                .map_err(|_| format!("'{}' is not a status code", token))
+        })
+        .collect()
+}
+
+pub fn compare(listed: &BTreeSet<u16>, status_codes: &BTreeMap<u16, Status>) -> Diff {
+    Diff {
+        missing: status_codes
+            .values()
+            .filter(|status| !listed.contains(&status.code))
+            .copied()
+            .collect(),
+        unknown: listed
+            .iter()
+            .filter(|code| !status_codes.contains_key(code))
+            .copied()
+            .collect(),
+    }
+}
+
+pub fn print(out: &mut dyn Write, diff: &Diff) -> io::Result<()> {
+    if diff.is_empty() {
+        return writeln!(out, "The list matches the known codes");
+    }
+    if !diff.missing.is_empty() {
+        writeln!(out, "Missing from the list ({}):", diff.missing.len())?;
+        for status in &diff.missing {
+            writeln!(out, "  - {}", output::status_line(status, false))?;
+        }
+    }
+    if !diff.unknown.is_empty() {
+        if !diff.missing.is_empty() {
+            writeln!(out)?;
+        }
+        writeln!(out, "Not known ({}):", diff.unknown.len())?;
+        for code in &diff.unknown {
+            writeln!(out, "  + {}", code)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare, parse_codes};
+    use httpstatus_rust::status_codes;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_parse_codes() {
+        let codes = parse_codes("200 404\n404\t500\n").unwrap();
+        assert_eq!(codes.into_iter().collect::<Vec<_>>(), [200, 404, 500]);
+        assert!(parse_codes("200 abc").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let known: BTreeMap<u16, _> = status_codes()
+            .range(200..=202)
+            .map(|(&code, &status)| (code, status))
+            .collect();
+        let diff = compare(&parse_codes("200 202 299").unwrap(), &known);
+        assert_eq!(diff.missing, [status_codes()[&201]]);
+        assert_eq!(diff.unknown, [299]);
+        assert!(compare(&parse_codes("200 201 202").unwrap(), &known).is_empty());
+    }
+}
@@ -0,0 +1,54 @@
+use std::process::Command;
+
+/// MDN reference pages live under this prefix, one per status code.
+const MDN_URL: &str = "https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/";
+
+/// Returns the MDN documentation page for a status code.
+pub fn mdn_url(code: u16) -> String {
+    format!("{}{}", MDN_URL, code)
+}
+
+/// The platform command that opens a URL in the default browser.
+fn opener_command(url: &str) -> Command {
+    if cfg!(target_os = "macos") {
+        let mut command = Command::new("open");
+        command.arg(url);
+        command
+    } else if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", "", url]);
+        command
+    } else {
+        let mut command = Command::new("xdg-open");
+        command.arg(url);
+        command
+    }
+}
+
+/// Opens `url` in the default browser. Returns false if no opener could be
+/// started or it reported a failure.
+pub fn open(url: &str) -> bool {
+    opener_command(url)
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mdn_url, opener_command};
+
+    #[test]
+    fn test_mdn_url() {
+        assert_eq!(
+            mdn_url(404),
+            "https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/404"
+        );
+    }
+
+    #[test]
+    fn test_opener_command_passes_url() {
+        let command = opener_command("https://example.com");
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(args.last().copied(), Some("https://example.com".as_ref()));
+    }
+}
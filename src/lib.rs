@@ -0,0 +1,28 @@
+//! HTTP status codes and their reason phrases.
+//!
+//! ```
+//! assert_eq!(httpstatus_rust::description(404), Some("Not Found"));
+//! ```
+
+mod aliases;
+mod class;
+mod codes;
+mod locale;
+mod status_code;
+mod validate;
+
+pub use aliases::alias_code;
+pub use class::{
+    class_counts, class_name, codes_in_class, describe_class, is_client_error, is_informational,
+    is_permanent_redirect, is_redirection, is_server_error, is_success, is_temporary_redirect,
+};
+pub use codes::{
+    SORTED_CODES, STATUS_CODE_COUNT, Source, Status, UNKNOWN_DESCRIPTION, contains, description,
+    description_or, description_or_unknown, is_retryable, iter, lookup_slice, reason_phrase,
+    search, status_codes, unofficial_status_codes,
+};
+pub use locale::{languages, localized_status_codes};
+#[cfg(feature = "http")]
+pub use status_code::canonical_reason;
+pub use status_code::{InvalidStatusCode, StatusCode};
+pub use validate::{EXPECTED_CLASS_COUNTS, self_check};
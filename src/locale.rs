@@ -0,0 +1,217 @@
+//! Translated reason phrases. A language is a table of `(code, phrase)` pairs
+//! registered in [`LANGUAGES`]; codes a table leaves out keep their English
+//! phrase.
+
+use crate::{Status, status_codes};
+use std::collections::BTreeMap;
+
+/// Language tags and their phrase tables. Add new languages here.
+static LANGUAGES: &[(&str, &[(u16, &str)])] = &[("de", GERMAN), ("es", SPANISH)];
+
+static GERMAN: &[(u16, &str)] = &[
+    (100, "Weiter"),
+    (101, "Protokollwechsel"),
+    (102, "Wird verarbeitet"),
+    (103, "Frühe Hinweise"),
+    (200, "OK"),
+    (201, "Erstellt"),
+    (202, "Akzeptiert"),
+    (203, "Nicht autoritative Information"),
+    (204, "Kein Inhalt"),
+    (205, "Inhalt zurücksetzen"),
+    (206, "Teilinhalt"),
+    (207, "Mehrfachstatus"),
+    (208, "Bereits gemeldet"),
+    (226, "IM verwendet"),
+    (300, "Mehrfachauswahl"),
+    (301, "Dauerhaft verschoben"),
+    (302, "Gefunden"),
+    (303, "Siehe andere"),
+    (304, "Nicht geändert"),
+    (305, "Proxy verwenden"),
+    (306, "Proxy wechseln"),
+    (307, "Temporäre Umleitung"),
+    (308, "Permanente Umleitung"),
+    (400, "Ungültige Anfrage"),
+    (401, "Nicht autorisiert"),
+    (402, "Zahlung erforderlich"),
+    (403, "Verboten"),
+    (404, "Nicht gefunden"),
+    (405, "Methode nicht erlaubt"),
+    (406, "Nicht annehmbar"),
+    (407, "Proxy-Authentifizierung erforderlich"),
+    (408, "Zeitüberschreitung der Anfrage"),
+    (409, "Konflikt"),
+    (410, "Verschwunden"),
+    (411, "Länge erforderlich"),
+    (412, "Vorbedingung fehlgeschlagen"),
+    (413, "Nutzlast zu groß"),
+    (414, "URI zu lang"),
+    (415, "Nicht unterstützter Medientyp"),
+    (416, "Bereich nicht erfüllbar"),
+    (417, "Erwartung fehlgeschlagen"),
+    (418, "Ich bin eine Teekanne"),
+    (421, "Fehlgeleitete Anfrage"),
+    (422, "Nicht verarbeitbare Entität"),
+    (423, "Gesperrt"),
+    (424, "Fehlgeschlagene Abhängigkeit"),
+    (425, "Zu früh"),
+    (426, "Upgrade erforderlich"),
+    (428, "Vorbedingung erforderlich"),
+    (429, "Zu viele Anfragen"),
+    (431, "Header-Felder der Anfrage zu groß"),
+    (451, "Aus rechtlichen Gründen nicht verfügbar"),
+    (500, "Interner Serverfehler"),
+    (501, "Nicht implementiert"),
+    (502, "Fehlerhaftes Gateway"),
+    (503, "Dienst nicht verfügbar"),
+    (504, "Gateway-Zeitüberschreitung"),
+    (505, "HTTP-Version nicht unterstützt"),
+    (506, "Variante verhandelt ebenfalls"),
+    (507, "Unzureichender Speicher"),
+    (508, "Schleife erkannt"),
+    (510, "Nicht erweitert"),
+    (511, "Netzwerkauthentifizierung erforderlich"),
+];
+
+static SPANISH: &[(u16, &str)] = &[
+    (100, "Continuar"),
+    (101, "Cambiando de protocolo"),
+    (102, "Procesando"),
+    (103, "Sugerencias tempranas"),
+    (200, "OK"),
+    (201, "Creado"),
+    (202, "Aceptado"),
+    (203, "Información no autoritativa"),
+    (204, "Sin contenido"),
+    (205, "Restablecer contenido"),
+    (206, "Contenido parcial"),
+    (207, "Multiestado"),
+    (208, "Ya reportado"),
+    (226, "IM usado"),
+    (300, "Múltiples opciones"),
+    (301, "Movido permanentemente"),
+    (302, "Encontrado"),
+    (303, "Ver otro"),
+    (304, "No modificado"),
+    (305, "Usar proxy"),
+    (306, "Cambiar proxy"),
+    (307, "Redirección temporal"),
+    (308, "Redirección permanente"),
+    (400, "Solicitud incorrecta"),
+    (401, "No autorizado"),
+    (402, "Pago requerido"),
+    (403, "Prohibido"),
+    (404, "No encontrado"),
+    (405, "Método no permitido"),
+    (406, "No aceptable"),
+    (407, "Autenticación de proxy requerida"),
+    (408, "Tiempo de espera de la solicitud agotado"),
+    (409, "Conflicto"),
+    (410, "Ya no disponible"),
+    (411, "Longitud requerida"),
+    (412, "Precondición fallida"),
+    (413, "Carga útil demasiado grande"),
+    (414, "URI demasiado largo"),
+    (415, "Tipo de medio no soportado"),
+    (416, "Rango no satisfacible"),
+    (417, "Expectativa fallida"),
+    (418, "Soy una tetera"),
+    (421, "Solicitud mal dirigida"),
+    (422, "Entidad no procesable"),
+    (423, "Bloqueado"),
+    (424, "Dependencia fallida"),
+    (425, "Demasiado pronto"),
+    (426, "Actualización requerida"),
+    (428, "Precondición requerida"),
+    (429, "Demasiadas solicitudes"),
+    (431, "Campos de encabezado de solicitud demasiado grandes"),
+    (451, "No disponible por razones legales"),
+    (500, "Error interno del servidor"),
+    (501, "No implementado"),
+    (502, "Puerta de enlace incorrecta"),
+    (503, "Servicio no disponible"),
+    (504, "Tiempo de espera de la puerta de enlace agotado"),
+    (505, "Versión de HTTP no soportada"),
+    (506, "La variante también negocia"),
+    (507, "Almacenamiento insuficiente"),
+    (508, "Bucle detectado"),
+    (510, "No extendido"),
+    (511, "Autenticación de red requerida"),
+];
+
+/// Returns the language tags that have translations, such as `es`.
+pub fn languages() -> impl Iterator<Item = &'static str> {
+    LANGUAGES.iter().map(|&(tag, _)| tag)
+}
+
+/// Replaces the descriptions in `codes` with the translated phrases.
+fn localize(
+    codes: &BTreeMap<u16, Status>,
+    phrases: &[(u16, &'static str)],
+) -> BTreeMap<u16, Status> {
+    let mut codes = codes.clone();
+    for &(code, phrase) in phrases {
+        if let Some(status) = codes.get_mut(&code) {
+            status.description = phrase;
+        }
+    }
+    codes
+}
+
+/// Returns [`status_codes`] with the reason phrases translated into `lang`,
+/// falling back to English for codes without a translation. Region subtags
+/// are ignored, so `es-MX` uses the Spanish table. Returns None for languages
+/// without translations.
+///
+/// ```
+/// let codes = httpstatus_rust::localized_status_codes("es").unwrap();
+/// assert_eq!(codes[&404].description, "No encontrado");
+/// ```
+pub fn localized_status_codes(lang: &str) -> Option<BTreeMap<u16, Status>> {
+    let primary = lang.split(['-', '_']).next().unwrap_or_default();
+    LANGUAGES
+        .iter()
+        .find(|&&(tag, _)| tag.eq_ignore_ascii_case(primary))
+        .map(|&(_, phrases)| localize(status_codes(), phrases))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LANGUAGES, languages, localize, localized_status_codes};
+    use crate::status_codes;
+
+    #[test]
+    fn test_localized_status_codes() {
+        let spanish = localized_status_codes("es").unwrap();
+        assert_eq!(spanish[&418].description, "Soy una tetera");
+        assert_eq!(spanish[&418].rfc, "RFC 2324");
+        assert_eq!(spanish.len(), status_codes().len());
+
+        let german = localized_status_codes("de-AT").unwrap();
+        assert_eq!(german[&404].description, "Nicht gefunden");
+        assert!(localized_status_codes("xx").is_none());
+    }
+
+    #[test]
+    fn test_missing_translations_fall_back_to_english() {
+        let codes = localize(status_codes(), &[(404, "No encontrado")]);
+        assert_eq!(codes[&404].description, "No encontrado");
+        assert_eq!(codes[&500].description, "Internal Server Error");
+    }
+
+    #[test]
+    fn test_tables_only_translate_registered_codes() {
+        for &(tag, phrases) in LANGUAGES {
+            for &(code, _) in phrases {
+                assert!(
+                    status_codes().contains_key(&code),
+                    "'{}' translates unregistered code {}",
+                    tag,
+                    code
+                );
+            }
+        }
+        assert_eq!(languages().collect::<Vec<_>>(), ["de", "es"]);
+    }
+}
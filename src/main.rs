@@ -1,236 +1,711 @@
-use comfy_table::presets::UTF8_BORDERS_ONLY;
-use comfy_table::{Cell, Color, Table};
+mod cli;
+mod clipboard;
+mod config;
+mod custom;
+mod diff;
+mod docs;
+mod output;
+mod repl;
+mod suggest;
+
+use clap::{Parser, ValueEnum};
+use cli::{Cli, ColorWhen, Format, Lookup, SortKey};
+use config::Config;
+use httpstatus_rust::{
+    Status, alias_code, languages, localized_status_codes, self_check, status_codes,
+    unofficial_status_codes,
+};
+use log::debug;
+use rand::seq::IteratorRandom;
 use std::collections::BTreeMap;
 use std::env;
-use std::io::Write;
-use std::process::{Command, Stdio};
-
-fn get_status_codes() -> BTreeMap<u16, &'static str> {
-    let mut map = BTreeMap::<u16, &'static str>::new();
-
-    // 1xx Informational
-    map.insert(100, "Continue");
-    map.insert(101, "Switching Protocols");
-    map.insert(102, "Processing");
-    map.insert(103, "Early Hints");
-
-    // 2xx Success
-    map.insert(200, "OK");
-    map.insert(201, "Created");
-    map.insert(202, "Accepted");
-    map.insert(203, "Non-Authoritative Information");
-    map.insert(204, "No Content");
-    map.insert(205, "Reset Content");
-    map.insert(206, "Partial Content");
-    map.insert(207, "Multi-Status");
-    map.insert(208, "Already Reported");
-    map.insert(226, "IM Used");
-
-    // 3xx Redirection
-    map.insert(300, "Multiple Choices");
-    map.insert(301, "Moved Permanently");
-    map.insert(302, "Found");
-    map.insert(303, "See Other");
-    map.insert(304, "Not Modified");
-    map.insert(305, "Use Proxy");
-    map.insert(306, "Switch Proxy");
-    map.insert(307, "Temporary Redirect");
-    map.insert(308, "Permanent Redirect");
-
-    // 4xx Client Error
-    map.insert(400, "Bad Request");
-    map.insert(401, "Unauthorized");
-    map.insert(402, "Payment Required");
-    map.insert(403, "Forbidden");
-    map.insert(404, "Not Found");
-    map.insert(405, "Method Not Allowed");
-    map.insert(406, "Not Acceptable");
-    map.insert(407, "Proxy Authentication Required");
-    map.insert(408, "Request Timeout");
-    map.insert(409, "Conflict");
-    map.insert(410, "Gone");
-    map.insert(411, "Length Required");
-    map.insert(412, "Precondition Failed");
-    map.insert(413, "Payload Too Large");
-    map.insert(414, "URI Too Long");
-    map.insert(415, "Unsupported Media Type");
-    map.insert(416, "Range Not Satisfiable");
-    map.insert(417, "Expectation Failed");
-    map.insert(418, "I'm a teapot");
-    map.insert(421, "Misdirected Request");
-    map.insert(422, "Unprocessable Entity");
-    map.insert(423, "Locked");
-    map.insert(424, "Failed Dependency");
-    map.insert(425, "Too Early");
-    map.insert(426, "Upgrade Required");
-    map.insert(428, "Precondition Required");
-    map.insert(429, "Too Many Requests");
-    map.insert(431, "Request Header Fields Too Large");
-    map.insert(451, "Unavailable For Legal Reasons");
-
-    // 5xx Server Error
-    map.insert(500, "Internal Server Error");
-    map.insert(501, "Not Implemented");
-    map.insert(502, "Bad Gateway");
-    map.insert(503, "Service Unavailable");
-    map.insert(504, "Gateway Timeout");
-    map.insert(505, "HTTP Version Not Supported");
-    map.insert(506, "Variant Also Negotiates");
-    map.insert(507, "Insufficient Storage");
-    map.insert(508, "Loop Detected");
-    map.insert(510, "Not Extended");
-    map.insert(511, "Network Authentication Required");
-
-    map
-}
-
-fn print_json(status_codes: &BTreeMap<u16, &'static str>) {
-    let json = match serde_json::to_string(status_codes) {
-        Ok(j) => j,
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufWriter, IsTerminal, Write};
+use std::process::ExitCode;
+
+/// Formats a "did you mean" hint, if there is anything to suggest.
+fn suggestion_hint(suggestions: &[Status]) -> Option<String> {
+    if suggestions.is_empty() {
+        return None;
+    }
+    let suggestions: Vec<String> = suggestions
+        .iter()
+        .map(|status| output::status_line(status, false))
+        .collect();
+    Some(format!("Did you mean: {}?", suggestions.join(", ")))
+}
+
+fn print_suggestions(suggestions: &[Status]) {
+    if let Some(hint) = suggestion_hint(suggestions) {
+        eprintln!("{}", hint);
+    }
+}
+
+/// Whether `description` contains `term`, ignoring case. This is the rule of
+/// `--search` and its highlighting, and the same one the library's `search`
+/// uses.
+pub(crate) fn matches(description: &str, term: &str) -> bool {
+    description.to_lowercase().contains(&term.to_lowercase())
+}
+
+/// Keeps the codes in `class` whose description [`matches`] `term`; a filter
+/// that is `None` keeps every code. With `invert`, keeps the codes that the
+/// two filters together leave out instead.
+fn select(
+    status_codes: &BTreeMap<u16, Status>,
+    class: Option<u16>,
+    term: Option<&str>,
+    invert: bool,
+) -> BTreeMap<u16, Status> {
+    status_codes
+        .iter()
+        .filter(|(_, status)| {
+            let selected = class.is_none_or(|digit| status.code / 100 == digit)
+                && term.is_none_or(|term| matches(status.description, term));
+            selected != invert
+        })
+        .map(|(&code, &status)| (code, status))
+        .collect()
+}
+
+/// Collects the statuses in the order they should be printed, reversed when
+/// `descending` is set.
+fn sorted_rows(
+    status_codes: &BTreeMap<u16, Status>,
+    sort: SortKey,
+    descending: bool,
+) -> Vec<Status> {
+    let mut rows: Vec<Status> = status_codes.values().copied().collect();
+    if sort == SortKey::Name {
+        rows.sort_by_key(|status| status.description.to_lowercase());
+    }
+    if descending {
+        rows.reverse();
+    }
+    rows
+}
+
+/// Keeps only the first `first` or last `last` rows, when either is given.
+fn limit_rows(mut rows: Vec<Status>, first: Option<usize>, last: Option<usize>) -> Vec<Status> {
+    if let Some(n) = first {
+        rows.truncate(n);
+    }
+    if let Some(n) = last {
+        rows.drain(..rows.len().saturating_sub(n));
+    }
+    rows
+}
+
+/// Describes one token read with `--stdin`: `404 Not Found` for known codes,
+/// with a marker for tokens that are unknown or not a code at all.
+fn annotate(token: &str, status_codes: &BTreeMap<u16, Status>) -> String {
+    match token.parse::<u16>() {
+        Ok(code) => match status_codes.get(&code) {
+            Some(status) => output::status_line(status, false),
+            None => format!("{} (unknown)", token),
+        },
+        Err(_) => format!("{} (invalid)", token),
+    }
+}
+
+/// Whether `token` is a code in `status_codes`.
+fn is_known(token: &str, status_codes: &BTreeMap<u16, Status>) -> bool {
+    token
+        .trim()
+        .parse::<u16>()
+        .is_ok_and(|code| status_codes.contains_key(&code))
+}
+
+/// Whether `token` names a code in `status_codes`, either as a number or as
+/// an informal name such as `teapot`, like the other options that take a code.
+fn is_valid_code(token: &str, status_codes: &BTreeMap<u16, Status>) -> bool {
+    let token = token.trim();
+    token
+        .parse::<u16>()
+        .ok()
+        .or_else(|| alias_code(token))
+        .is_some_and(|code| status_codes.contains_key(&code))
+}
+
+/// Annotates every token of `input`. With `strict`, stops at the first token
+/// that is not a known code and returns it instead.
+fn annotate_lines(
+    input: impl BufRead,
+    out: &mut dyn Write,
+    status_codes: &BTreeMap<u16, Status>,
+    strict: bool,
+) -> io::Result<Option<String>> {
+    for line in input.lines() {
+        for token in line?.split_whitespace() {
+            if strict && !is_known(token, status_codes) {
+                return Ok(Some(token.to_string()));
+            }
+            writeln!(out, "{}", annotate(token, status_codes))?;
+        }
+    }
+    Ok(None)
+}
+
+/// Exit status for a looked-up code that is not in the table.
+const EXIT_UNKNOWN_CODE: u8 = 2;
+
+/// Exit status for a looked-up code outside 1xx-5xx, such as a custom 6xx code.
+const EXIT_NONSTANDARD_CLASS: u8 = 6;
+
+/// Exit status for a `--diff` file that cannot be read, as diff(1) uses 2 for
+/// trouble and 1 for differences.
+const EXIT_DIFF_TROUBLE: u8 = 2;
+
+/// With `--color auto`, colors are used only when `NO_COLOR` is unset and the
+/// output goes to a terminal, so logs and files never pick up escape codes by
+/// accident. Files written with `--output` never get colors, even with
+/// `--color always`.
+fn use_color(when: ColorWhen, to_stdout: bool) -> bool {
+    match when {
+        ColorWhen::Always => to_stdout,
+        ColorWhen::Never => false,
+        ColorWhen::Auto => {
+            to_stdout
+                && env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+                && io::stdout().is_terminal()
+        }
+    }
+}
+
+/// The format to use when no format flag is given: `HTTPSTATUS_FORMAT`, then
+/// the config file. None when neither picks one.
+fn default_format(config: Option<Format>, quiet: bool) -> Option<Format> {
+    match env::var("HTTPSTATUS_FORMAT") {
+        Ok(value) if !value.is_empty() => Format::from_str(&value, true).map_or_else(
+            |_| {
+                if !quiet {
+                    eprintln!("Ignoring unknown HTTPSTATUS_FORMAT '{}'", value);
+                }
+                None
+            },
+            Some,
+        ),
+        _ => config,
+    }
+}
+
+/// The format used when nothing picks one: the table on a terminal or when a
+/// table-only option asks for it, and otherwise plain lines, so captured
+/// output has no box-drawing characters.
+fn fallback_format(terminal: bool) -> Format {
+    if terminal {
+        Format::Table
+    } else {
+        Format::Plain
+    }
+}
+
+/// Maps a looked-up code to an exit status derived from its class, so that
+/// `httpstatus 500 && echo ok` only succeeds for 2xx codes. Known codes
+/// outside the standard classes, such as 6xx codes from `--codes-file`, exit
+/// with 6, never with the unknown-code status.
+fn lookup_exit_code(code: u16) -> u8 {
+    match code / 100 {
+        2 => 0,
+        class @ 1..=5 => class as u8,
+        _ => EXIT_NONSTANDARD_CLASS,
+    }
+}
+
+fn main() -> ExitCode {
+    env_logger::init();
+    let cli = Cli::parse();
+
+    if let Some(shell) = cli.completions {
+        cli::print_completions(shell);
+        return ExitCode::SUCCESS;
+    }
+
+    let config = match config::load() {
+        Ok(config) => config,
         Err(e) => {
-            eprintln!("Failed to serialize JSON: {}", e);
-            return;
+            eprintln!("Failed to read config file {}", e);
+            return ExitCode::FAILURE;
         }
     };
 
-    let child = Command::new("jq").arg(".").stdin(Stdio::piped()).spawn();
-    match child {
-        Ok(mut process) => {
-            if let Some(mut stdin) = process.stdin.take() {
-                let _ = stdin.write_all(json.as_bytes());
+    let mut out: Box<dyn Write> = match &cli.output {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(BufWriter::new(file)),
+            Err(e) => {
+                eprintln!("Failed to create {}: {}", path.display(), e);
+                return ExitCode::FAILURE;
             }
-            let _ = process.wait();
-        }
-        Err(_) => {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(status_codes).unwrap_or(json)
-            );
+        },
+        None => Box::new(io::stdout().lock()),
+    };
+
+    match run(cli, config, &mut out).and_then(|code| out.flush().map(|()| code)) {
+        Ok(code) => code,
+        // The reader went away, as with `httpstatus | head`.
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Failed to write output: {}", e);
+            ExitCode::FAILURE
         }
     }
 }
 
-fn print_table(status_codes: &BTreeMap<u16, &'static str>) {
-    let mut table = Table::new();
-    table.load_preset(UTF8_BORDERS_ONLY);
-    table.set_header(vec![
-        Cell::new("Code").fg(Color::Cyan),
-        Cell::new("Description").fg(Color::Yellow),
-    ]);
+/// Runs the selected mode, writing its results to `out`. Colors, jq and the
+/// pager are only used when `out` is stdout.
+///
+/// Errors that come with a non-zero exit status are always reported on
+/// stderr. Hints and warnings that leave the exit status alone are dropped
+/// with `--quiet`.
+fn run(mut cli: Cli, config: Config, out: &mut dyn Write) -> io::Result<ExitCode> {
+    if !cli.no_extended {
+        cli.extended |= config.extended.unwrap_or(false);
+    }
+    if cli.copy && !matches!(cli.codes[..], [Lookup::Code(_)]) {
+        eprintln!("--copy needs exactly one code, such as `httpstatus 404 --copy`");
+        return Ok(ExitCode::FAILURE);
+    }
+    // Checked before the config fills in a theme, which should not turn the
+    // table on by itself.
+    let wants_table = cli.wants_table();
+    cli.theme = cli.theme.or(config.theme);
+    let mut status_codes = match cli.lang.as_deref() {
+        Some(lang) => localized_status_codes(lang).unwrap_or_else(|| {
+            if !cli.quiet {
+                let available: Vec<&str> = languages().collect();
+                eprintln!(
+                    "No translations for '{}', using English (available: {})",
+                    lang,
+                    available.join(", ")
+                );
+            }
+            status_codes().clone()
+        }),
+        None => status_codes().clone(),
+    };
+    let to_stdout = cli.output.is_none();
+    let chosen_format = cli
+        .format()
+        .or_else(|| default_format(config.format, cli.quiet));
+    let format = chosen_format.unwrap_or_else(|| {
+        fallback_format(wants_table || (to_stdout && io::stdout().is_terminal()))
+    });
+    let rfc = cli.rfc;
+    let options = output::Options {
+        rfc,
+        color: use_color(cli.color_when(), to_stdout),
+        columns: cli.columns(),
+        #[cfg(feature = "external-jq")]
+        jq: to_stdout && cli.jq,
+        pager: to_stdout && cli.pager,
+        source: cli.shows_source(),
+        highlight: cli.search.clone(),
+        summary: cli.summary,
+        objects: cli.json_objects,
+        legend: cli.legend,
+        emoji: cli.emoji,
+        #[cfg(feature = "external-jq")]
+        quiet: cli.quiet,
+        border: cli.border.unwrap_or_default(),
+        theme: output::Theme::preset(cli.theme.unwrap_or_default()),
+        wrap: cli
+            .wrap
+            .or_else(|| to_stdout.then(output::terminal_width).flatten()),
+        descending: cli.sort_desc,
+    };
+    debug!(
+        "Using the {:?} format, colors {}",
+        format,
+        if options.color { "on" } else { "off" }
+    );
+    if cli.extended {
+        status_codes.extend(unofficial_status_codes());
+    }
+    if let Some(path) = &cli.codes_file {
+        let custom = match custom::load(path) {
+            Ok(custom) => custom,
+            Err(e) => {
+                eprintln!("Failed to read codes file {}", e);
+                return Ok(ExitCode::FAILURE);
+            }
+        };
+        for (existing, status) in custom::merge(&mut status_codes, custom) {
+            if !cli.quiet {
+                eprintln!(
+                    "{} overrides {} {} with '{}'",
+                    path.display(),
+                    existing.code,
+                    existing.description,
+                    status.description
+                );
+            }
+        }
+    }
+
+    if cli.selfcheck {
+        let statuses: Vec<Status> = status_codes.values().copied().collect();
+        let mut passed = true;
+        for (name, result) in self_check(&statuses) {
+            match result {
+                Ok(()) => writeln!(out, "ok    {}", name)?,
+                Err(e) => {
+                    writeln!(out, "FAIL  {}: {}", name, e)?;
+                    passed = false;
+                }
+            }
+        }
+        return Ok(if passed {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        });
+    }
 
-    for (&code, &description) in status_codes {
-        table.add_row(vec![
-            Cell::new(code.to_string()).fg(Color::Red),
-            Cell::new(description).fg(Color::Green),
-        ]);
+    if cli.schema {
+        output::print_schema(out, &options)?;
+        return Ok(ExitCode::SUCCESS);
     }
 
-    println!("{}", table);
-}
+    if let Some(token) = &cli.is_valid {
+        return Ok(if is_valid_code(token, &status_codes) {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        });
+    }
 
-fn main() {
-    let status_codes = get_status_codes();
-    let args: Vec<String> = env::args().collect();
+    if cli.stdin {
+        return match annotate_lines(io::stdin().lock(), out, &status_codes, cli.strict) {
+            Ok(None) => Ok(ExitCode::SUCCESS),
+            Ok(Some(token)) => {
+                eprintln!("Unknown status code: {}", token);
+                Ok(ExitCode::from(EXIT_UNKNOWN_CODE))
+            }
+            Err(e) => {
+                eprintln!("Failed to read stdin: {}", e);
+                Ok(ExitCode::FAILURE)
+            }
+        };
+    }
+
+    if cli.interactive {
+        return match repl::run(&status_codes, rfc) {
+            Ok(()) => Ok(ExitCode::SUCCESS),
+            Err(e) => {
+                eprintln!("Failed to read input: {}", e);
+                Ok(ExitCode::FAILURE)
+            }
+        };
+    }
+
+    if let Some(digit) = cli.class_info {
+        output::print_class_info(out, digit, options.wrap)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(code) = cli.explain {
+        let Some(status) = status_codes.get(&code) else {
+            eprintln!("Unknown status code: {}", code);
+            return Ok(ExitCode::FAILURE);
+        };
+        output::print_explanation(out, status, options.wrap)?;
+        return Ok(ExitCode::SUCCESS);
+    }
 
-    if args.len() > 1 && (args[1] == "--json" || args[1] == "-j") {
-        print_json(&status_codes);
+    if let Some(code) = cli.examples {
+        let Some(status) = status_codes.get(&code) else {
+            eprintln!("Unknown status code: {}", code);
+            return Ok(ExitCode::FAILURE);
+        };
+        output::print_example(out, status)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(code) = cli.help_code {
+        if !status_codes.contains_key(&code) {
+            eprintln!("Unknown status code: {}", code);
+            return Ok(ExitCode::FAILURE);
+        }
+        let url = docs::mdn_url(code);
+        writeln!(out, "{}", url)?;
+        if cli.open && !docs::open(&url) {
+            eprintln!("Could not open {} in a browser", url);
+            return Ok(ExitCode::FAILURE);
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let [Lookup::Code(code)] = cli.codes[..] {
+        let Some(&status) = status_codes.get(&code) else {
+            eprintln!("Unknown status code: {}", code);
+            if !cli.quiet {
+                print_suggestions(&suggest::similar_codes(code, &status_codes));
+                let nearest: Vec<String> = suggest::nearest_codes(code, &status_codes)
+                    .iter()
+                    .map(u16::to_string)
+                    .collect();
+                if !nearest.is_empty() {
+                    eprintln!("Nearest known codes: {}", nearest.join(", "));
+                }
+            }
+            return Ok(ExitCode::from(EXIT_UNKNOWN_CODE));
+        };
+
+        // A lone lookup prints `404 Not Found` unless a format was asked for.
+        if format == Format::Table || chosen_format.is_none() {
+            writeln!(out, "{}", output::status_line(&status, rfc))?;
+        } else {
+            output::print(out, format, &[status], &options)?;
+        }
+        if cli.copy
+            && let Err(e) = clipboard::copy(&output::status_line(&status, false))
+            && !cli.quiet
+        {
+            eprintln!("Could not copy to the clipboard: {}", e);
+        }
+        return Ok(ExitCode::from(lookup_exit_code(code)));
+    }
+
+    if !cli.codes.is_empty() {
+        let mut found = BTreeMap::new();
+        let mut all_known = true;
+        for &lookup in &cli.codes {
+            match lookup {
+                Lookup::Code(code) => match status_codes.get(&code) {
+                    Some(&status) => {
+                        found.insert(code, status);
+                    }
+                    None => {
+                        eprintln!("Unknown status code: {}", code);
+                        all_known = false;
+                    }
+                },
+                Lookup::Range(start, end) => {
+                    let before = found.len();
+                    found.extend(status_codes.range(start..=end));
+                    if found.len() == before && !cli.quiet {
+                        eprintln!("No known status codes in {}-{}", start, end);
+                    }
+                }
+            }
+        }
+
+        if !found.is_empty() {
+            let rows = sorted_rows(&found, cli.sort, cli.sort_desc);
+            let rows = limit_rows(rows, cli.first, cli.last);
+            output::print(out, format, &rows, &options)?;
+        }
+        return Ok(if all_known {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::from(EXIT_UNKNOWN_CODE)
+        });
+    }
+
+    if cli.hide_deprecated {
+        status_codes.retain(|_, status| !status.deprecated);
+    }
+    if cli.class.is_some() || cli.search.is_some() {
+        let selected = select(&status_codes, cli.class, cli.search.as_deref(), cli.invert);
+        if let Some(term) = &cli.search
+            && selected.is_empty()
+            && cli.diff.is_none()
+        {
+            if cli.invert {
+                eprintln!("No status codes are left out by '{}'", term);
+            } else {
+                eprintln!("No status codes match '{}'", term);
+                if !cli.quiet {
+                    let in_class = select(&status_codes, cli.class, None, false);
+                    print_suggestions(&suggest::similar_descriptions(term, &in_class));
+                }
+            }
+            return Ok(ExitCode::FAILURE);
+        }
+        status_codes = selected;
+    }
+
+    if let Some(path) = &cli.diff {
+        let listed = match fs::read_to_string(path) {
+            Ok(text) => diff::parse_codes(&text),
+            Err(e) => Err(e.to_string()),
+        };
+        let listed = match listed {
+            Ok(listed) => listed,
+            Err(e) => {
+                eprintln!("Failed to read {}: {}", path.display(), e);
+                return Ok(ExitCode::from(EXIT_DIFF_TROUBLE));
+            }
+        };
+        let diff = diff::compare(&listed, &status_codes);
+        diff::print(out, &diff)?;
+        return Ok(if diff.is_empty() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        });
+    }
+
+    if cli.random {
+        let Some(&status) = status_codes.values().choose(&mut rand::rng()) else {
+            eprintln!("No status codes to choose from");
+            return Ok(ExitCode::FAILURE);
+        };
+        status_codes = BTreeMap::from([(status.code, status)]);
+        if format == Format::Table || chosen_format.is_none() {
+            writeln!(out, "{}", output::status_line(&status, rfc))?;
+            return Ok(ExitCode::SUCCESS);
+        }
+    }
+
+    let rows = sorted_rows(&status_codes, cli.sort, cli.sort_desc);
+    let rows = limit_rows(rows, cli.first, cli.last);
+    if cli.count {
+        output::print_counts(out, &rows)?;
+    } else if cli.banner {
+        output::print_banner(out, &rows)?;
+    } else if cli.prometheus {
+        output::print_prometheus(out, &rows)?;
+    } else if cli.grouped {
+        output::print_grouped(out, &rows, &options)?;
     } else {
-        print_table(&status_codes);
+        output::print(out, format, &rows, &options)?;
     }
+    Ok(ExitCode::SUCCESS)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::get_status_codes;
+    use crate::cli::{ColorWhen, Format, SortKey};
+    use crate::{
+        EXIT_NONSTANDARD_CLASS, EXIT_UNKNOWN_CODE, annotate, annotate_lines, fallback_format,
+        is_known, is_valid_code, limit_rows, lookup_exit_code, select, sorted_rows, use_color,
+    };
+    use httpstatus_rust::{STATUS_CODE_COUNT, Status, status_codes, unofficial_status_codes};
+    use std::collections::BTreeMap;
 
     #[test]
-    fn test_status_codes_count() {
-        let status_codes = get_status_codes();
-        assert_eq!(status_codes.len(), 63, "Should have 63 HTTP status codes")
+    fn test_lookup_exit_code() {
+        assert_eq!(lookup_exit_code(101), 1);
+        assert_eq!(lookup_exit_code(200), 0);
+        assert_eq!(lookup_exit_code(204), 0);
+        assert_eq!(lookup_exit_code(301), 3);
+        assert_eq!(lookup_exit_code(404), 4);
+        assert_eq!(lookup_exit_code(503), 5);
+        assert_eq!(lookup_exit_code(600), EXIT_NONSTANDARD_CLASS);
+        assert_eq!(lookup_exit_code(999), EXIT_NONSTANDARD_CLASS);
+        assert_ne!(EXIT_NONSTANDARD_CLASS, EXIT_UNKNOWN_CODE);
     }
 
     #[test]
-    fn test_status_codes_order() {
-        let status_codes = get_status_codes();
-        let codes: Vec<u16> = status_codes.keys().copied().collect();
-        for i in 1..codes.len() {
-            assert!(
-                codes[i - 1] < codes[i],
-                "Codes should be in ascending order"
-            )
-        }
+    fn test_annotate() {
+        assert_eq!(annotate("404", status_codes()), "404 Not Found");
+        assert_eq!(annotate("999", status_codes()), "999 (unknown)");
+        assert_eq!(annotate("abc", status_codes()), "abc (invalid)");
+    }
+
+    #[test]
+    fn test_is_known() {
+        assert!(is_known("404", status_codes()));
+        assert!(!is_known("499", status_codes()));
+        assert!(is_known("499", unofficial_status_codes()));
+        assert!(!is_known("abc", status_codes()));
+    }
+
+    #[test]
+    fn test_is_valid_code() {
+        assert!(is_valid_code("404", status_codes()));
+        assert!(is_valid_code("teapot", status_codes()));
+        assert!(is_valid_code(" teapot ", status_codes()));
+        assert!(!is_valid_code("banana", status_codes()));
+        assert!(!is_valid_code("499", status_codes()));
     }
 
     #[test]
-    fn test_common_status_codes() {
-        let status_codes = get_status_codes();
+    fn test_annotate_lines() {
+        let input = "200 999\n404 abc\n";
+        let mut out = Vec::new();
+        let stopped = annotate_lines(input.as_bytes(), &mut out, status_codes(), false).unwrap();
+        assert_eq!(stopped, None);
+        assert_eq!(String::from_utf8(out).unwrap().lines().count(), 4);
 
-        assert_eq!(status_codes.get(&200), Some(&"OK"));
-        assert_eq!(status_codes.get(&302), Some(&"Found"));
-        assert_eq!(status_codes.get(&404), Some(&"Not Found"));
-        assert_eq!(status_codes.get(&418), Some(&"I'm a teapot"));
+        let mut out = Vec::new();
+        let stopped = annotate_lines(input.as_bytes(), &mut out, status_codes(), true).unwrap();
+        assert_eq!(stopped.as_deref(), Some("999"));
+        assert_eq!(String::from_utf8(out).unwrap(), "200 OK\n");
     }
 
     #[test]
-    fn test_status_code_ranges() {
-        let status_codes = get_status_codes();
-        let count_1xx = status_codes
-            .keys()
-            .filter(|&&c| (100..200).contains(&c))
-            .count();
-        let count_2xx = status_codes
-            .keys()
-            .filter(|&&c| (200..300).contains(&c))
-            .count();
-        let count_3xx = status_codes
-            .keys()
-            .filter(|&&c| (300..400).contains(&c))
-            .count();
-        let count_4xx = status_codes
-            .keys()
-            .filter(|&&c| (400..500).contains(&c))
-            .count();
-        let count_5xx = status_codes
-            .keys()
-            .filter(|&&c| (500..600).contains(&c))
-            .count();
-
-        assert_eq!(count_1xx, 4, "Should have 4 informational codes");
-        assert_eq!(count_2xx, 10, "Should have 10 success codes");
-        assert_eq!(count_3xx, 9, "Should have 9 redirection codes");
-        assert_eq!(count_4xx, 29, "Should have 29 client error codes");
-        assert_eq!(count_5xx, 11, "Should have 11 client error codes");
+    fn test_sorted_rows() {
+        let by_code = sorted_rows(status_codes(), SortKey::Code, false);
+        assert!(by_code.windows(2).all(|pair| pair[0].code < pair[1].code));
+
+        let by_name = sorted_rows(status_codes(), SortKey::Name, false);
+        assert_eq!(by_name.len(), by_code.len());
+        assert_eq!(by_name[0].description, "Accepted");
+        assert_eq!(
+            by_name.last().unwrap().description,
+            "Variant Also Negotiates"
+        );
     }
 
     #[test]
-    fn test_non_existent_status_code() {
-        let status_codes = get_status_codes();
-        assert!(!status_codes.contains_key(&999));
-        assert!(!status_codes.contains_key(&666));
+    fn test_use_color() {
+        assert!(use_color(ColorWhen::Always, true));
+        assert!(!use_color(ColorWhen::Always, false));
+        assert!(!use_color(ColorWhen::Never, true));
+        assert!(!use_color(ColorWhen::Auto, false));
     }
 
     #[test]
-    fn test_all_values_are_non_empty() {
-        let status_codes = get_status_codes();
-        for (code, description) in &status_codes {
-            assert!(
-                !description.is_empty(),
-                "Code {} has an empty description",
-                code
-            )
-        }
+    fn test_fallback_format() {
+        assert_eq!(fallback_format(true), Format::Table);
+        assert_eq!(fallback_format(false), Format::Plain);
+    }
+
+    #[test]
+    fn test_select() {
+        let codes = |map: BTreeMap<u16, Status>| map.into_keys().collect::<Vec<_>>();
+        assert_eq!(
+            codes(select(status_codes(), Some(2), Some("ok"), false)),
+            [200]
+        );
+        assert_eq!(
+            codes(select(status_codes(), None, Some("GATEWAY"), false)),
+            [502, 504]
+        );
+
+        // The complement of both filters together, not of each one in turn.
+        let inverted = select(status_codes(), Some(2), Some("ok"), true);
+        assert_eq!(inverted.len(), STATUS_CODE_COUNT - 1);
+        assert!(!inverted.contains_key(&200));
+        assert!(inverted.contains_key(&201));
+        assert!(inverted.contains_key(&404));
+
+        assert!(select(status_codes(), None, Some(""), true).is_empty());
     }
 
     #[test]
-    fn test_json_output_is_valid() {
-        let status_codes = get_status_codes();
-        let json = serde_json::to_string(&status_codes);
-        assert!(json.is_ok(), "Should serialize to valid JSON");
-
-        let json_str = json.unwrap();
-        assert!(json_str.starts_with('{'));
-        assert!(json_str.ends_with('}'));
-        assert!(json_str.contains("\"100\""));
-        assert!(json_str.contains("\"500\""));
+    fn test_limit_rows() {
+        let codes = |rows: Vec<Status>| rows.iter().map(|s| s.code).collect::<Vec<_>>();
+        let rows = || sorted_rows(status_codes(), SortKey::Code, false);
+        assert_eq!(codes(limit_rows(rows(), Some(2), None)), [100, 101]);
+        assert_eq!(codes(limit_rows(rows(), None, Some(2))), [510, 511]);
+        assert_eq!(limit_rows(rows(), None, Some(1000)).len(), rows().len());
+        assert!(limit_rows(rows(), Some(0), None).is_empty());
+    }
+
+    #[test]
+    fn test_sorted_rows_descending() {
+        let descending = sorted_rows(status_codes(), SortKey::Code, true);
+        assert!(
+            descending
+                .windows(2)
+                .all(|pair| pair[0].code > pair[1].code)
+        );
+        assert_eq!(descending[0].code, 511);
+
+        let by_name = sorted_rows(status_codes(), SortKey::Name, true);
+        assert_eq!(by_name[0].description, "Variant Also Negotiates");
     }
 }
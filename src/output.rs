@@ -0,0 +1,1703 @@
+use crate::cli::{Border, Column, Format, ThemeName};
+use crate::matches;
+use comfy_table::presets::{ASCII_BORDERS_ONLY, ASCII_FULL, NOTHING, UTF8_BORDERS_ONLY, UTF8_FULL};
+use comfy_table::{Cell, Color, ContentArrangement, Table};
+use httpstatus_rust::{
+    Source, Status, class_name, describe_class, is_permanent_redirect, is_temporary_redirect,
+};
+use log::debug;
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+use std::env;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+/// The JSON Schema dialect of `--schema`.
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Hundreds digits of the status classes.
+const CLASSES: [u16; 5] = [1, 2, 3, 4, 5];
+
+/// Maps each code to its description, or to an object that also carries the
+/// RFC reference when `rfc` is set and the source when `source` is set.
+/// Entries keep the order of `statuses`.
+fn json_entries(statuses: &[Status], rfc: bool, source: bool) -> Map<String, Value> {
+    statuses
+        .iter()
+        .map(|status| {
+            let value = if rfc || source {
+                let mut entry = json!({ "description": status.description });
+                if rfc {
+                    entry["rfc"] = json!(status.rfc);
+                }
+                if source {
+                    entry["source"] = json!(status.source.as_str());
+                }
+                entry
+            } else {
+                json!(status.description)
+            };
+            (status.code.to_string(), value)
+        })
+        .collect()
+}
+
+/// Maps each code to an object that repeats the code and names its class, such
+/// as `{"code": 404, "description": "Not Found", "class": "4xx"}`, with the RFC
+/// reference and source added as for [`json_entries`].
+fn json_object_entries(statuses: &[Status], rfc: bool, source: bool) -> Map<String, Value> {
+    statuses
+        .iter()
+        .map(|status| {
+            let mut entry = json!({
+                "code": status.code,
+                "description": status.description,
+                "class": format!("{}xx", status.code / 100),
+            });
+            if rfc {
+                entry["rfc"] = json!(status.rfc);
+            }
+            if source {
+                entry["source"] = json!(status.source.as_str());
+            }
+            (status.code.to_string(), entry)
+        })
+        .collect()
+}
+
+/// Writes `text` to the stdin of `command` and waits for it to exit. Returns
+/// false if the command could not be started.
+fn pipe_through(command: &mut Command, text: &str) -> bool {
+    let program = command.get_program().to_string_lossy().into_owned();
+    match command.stdin(Stdio::piped()).spawn() {
+        Ok(mut process) => {
+            debug!("Piping output through {}", program);
+            if let Some(mut stdin) = process.stdin.take() {
+                // Quitting a pager early closes the pipe, which is not an error.
+                if let Err(e) = stdin.write_all(text.as_bytes()) {
+                    debug!("Stopped writing to {}: {}", program, e);
+                }
+            }
+            match process.wait() {
+                Ok(status) if !status.success() => debug!("{} exited with {}", program, status),
+                Ok(_) => {}
+                Err(e) => debug!("Failed to wait for {}: {}", program, e),
+            }
+            true
+        }
+        Err(e) => {
+            debug!("Could not start {}: {}", program, e);
+            false
+        }
+    }
+}
+
+/// The JSON entries, or with `summary` an object that also carries the
+/// per-class counts: `{"codes": {...}, "summary": {"4xx": 29, ...}}`.
+fn json_value(statuses: &[Status], options: &Options) -> Value {
+    let entries = if options.objects {
+        json_object_entries(statuses, options.rfc, options.source)
+    } else {
+        json_entries(statuses, options.rfc, options.source)
+    };
+    let entries = Value::Object(entries);
+    if !options.summary {
+        return entries;
+    }
+    let counts: Map<String, Value> = count_by_class(statuses)
+        .into_iter()
+        .map(|(digit, count)| (format!("{}xx", digit), json!(count)))
+        .collect();
+    json!({ "codes": entries, "summary": counts })
+}
+
+/// A JSON Schema (draft 2020-12) of what [`json_value`] produces for the same
+/// options, so consumers can validate the output.
+fn json_schema(options: &Options) -> Value {
+    let entry = if options.objects || options.rfc || options.source {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        if options.objects {
+            properties.insert("code".to_string(), json!({ "type": "integer" }));
+            required.push(json!("code"));
+        }
+        properties.insert("description".to_string(), json!({ "type": "string" }));
+        required.push(json!("description"));
+        if options.objects {
+            let class = json!({ "type": "string", "pattern": "^[1-9]xx$" });
+            properties.insert("class".to_string(), class);
+            required.push(json!("class"));
+        }
+        if options.rfc {
+            properties.insert("rfc".to_string(), json!({ "type": "string" }));
+            required.push(json!("rfc"));
+        }
+        if options.source {
+            let sources = [Source::Iana.as_str(), Source::Unofficial.as_str()];
+            properties.insert("source".to_string(), json!({ "enum": sources }));
+            required.push(json!("source"));
+        }
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false
+        })
+    } else {
+        json!({ "type": "string", "description": "The reason phrase" })
+    };
+    let code_names = json!({ "pattern": "^[1-9][0-9]{2}$" });
+    if !options.summary {
+        return json!({
+            "$schema": SCHEMA_DIALECT,
+            "title": "httpstatus JSON output",
+            "type": "object",
+            "propertyNames": code_names,
+            "additionalProperties": entry
+        });
+    }
+    json!({
+        "$schema": SCHEMA_DIALECT,
+        "title": "httpstatus JSON output with summary",
+        "type": "object",
+        "properties": {
+            "codes": {
+                "type": "object",
+                "propertyNames": code_names,
+                "additionalProperties": entry
+            },
+            "summary": {
+                "type": "object",
+                "propertyNames": { "pattern": "^[1-9]xx$" },
+                "additionalProperties": { "type": "integer", "minimum": 0 }
+            }
+        },
+        "required": ["codes", "summary"],
+        "additionalProperties": false
+    })
+}
+
+pub fn print_schema(out: &mut dyn Write, options: &Options) -> io::Result<()> {
+    writeln!(
+        out,
+        "{}",
+        serde_json::to_string_pretty(&json_schema(options))?
+    )
+}
+
+/// Serializes the entries as pretty-printed JSON.
+fn json_document(statuses: &[Status], options: &Options) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(&json_value(statuses, options))
+}
+
+fn print_json(out: &mut dyn Write, statuses: &[Status], options: &Options) -> io::Result<()> {
+    let json = json_document(statuses, options)?;
+    #[cfg(feature = "external-jq")]
+    if options.jq {
+        if pipe_through(Command::new("jq").arg("."), &json) {
+            return Ok(());
+        }
+        if !options.quiet {
+            eprintln!("jq not found, using built-in pretty printer");
+        }
+    }
+    writeln!(out, "{}", json)
+}
+
+/// Writes the entries as compact JSON on a single line, for byte-for-byte
+/// reproducible output.
+fn print_minimal_json(
+    out: &mut dyn Write,
+    statuses: &[Status],
+    options: &Options,
+) -> io::Result<()> {
+    let value = json_value(statuses, options);
+    writeln!(out, "{}", serde_json::to_string(&value)?)
+}
+
+/// Serializes each status as a one-line JSON object, such as
+/// `{"code":404,"description":"Not Found"}`.
+fn jsonl_lines(statuses: &[Status], rfc: bool, source: bool) -> serde_json::Result<Vec<String>> {
+    statuses
+        .iter()
+        .map(|status| {
+            let mut entry = json!({ "code": status.code, "description": status.description });
+            if rfc {
+                entry["rfc"] = json!(status.rfc);
+            }
+            if source {
+                entry["source"] = json!(status.source.as_str());
+            }
+            serde_json::to_string(&entry)
+        })
+        .collect()
+}
+
+fn print_jsonl(
+    out: &mut dyn Write,
+    statuses: &[Status],
+    rfc: bool,
+    source: bool,
+) -> io::Result<()> {
+    for line in jsonl_lines(statuses, rfc, source)? {
+        writeln!(out, "{}", line)?;
+    }
+    Ok(())
+}
+
+/// Uses the JSON entries, whose string keys keep YAML parsers from reading the
+/// codes back as integers.
+fn print_yaml(out: &mut dyn Write, statuses: &[Status], rfc: bool, source: bool) -> io::Result<()> {
+    match serde_yaml::to_string(&json_entries(statuses, rfc, source)) {
+        Ok(yaml) => write!(out, "{}", yaml),
+        Err(e) => Err(io::Error::other(format!("Failed to serialize YAML: {}", e))),
+    }
+}
+
+/// Applies a foreground color to a cell unless colors are disabled.
+fn paint(cell: Cell, color: Color, enabled: bool) -> Cell {
+    if enabled { cell.fg(color) } else { cell }
+}
+
+/// Renders the codes under a `[codes]` table. The keys are written quoted by
+/// hand, since a document of bare numeric keys trips up some TOML readers;
+/// values are encoded by the `toml` crate.
+fn toml_document(statuses: &[Status], rfc: bool, source: bool) -> String {
+    let value = |text: &str| toml::Value::from(text).to_string();
+    let mut document = String::new();
+    if rfc || source {
+        for status in statuses {
+            if !document.is_empty() {
+                document.push('\n');
+            }
+            document.push_str(&format!("[codes.\"{}\"]\n", status.code));
+            document.push_str(&format!("description = {}\n", value(status.description)));
+            if rfc {
+                document.push_str(&format!("rfc = {}\n", value(status.rfc)));
+            }
+            if source {
+                document.push_str(&format!("source = {}\n", value(status.source.as_str())));
+            }
+        }
+    } else {
+        document.push_str("[codes]\n");
+        for status in statuses {
+            document.push_str(&format!(
+                "\"{}\" = {}\n",
+                status.code,
+                value(status.description)
+            ));
+        }
+    }
+    document
+}
+
+fn print_toml(out: &mut dyn Write, statuses: &[Status], rfc: bool, source: bool) -> io::Result<()> {
+    write!(out, "{}", toml_document(statuses, rfc, source))
+}
+
+/// Rendering choices shared by the output formats.
+#[derive(Default)]
+pub struct Options {
+    pub rfc: bool,
+    pub color: bool,
+    pub columns: Vec<Column>,
+    /// Pretty-print JSON through jq when it is installed, for `--jq`.
+    #[cfg(feature = "external-jq")]
+    pub jq: bool,
+    /// Show tables through `$PAGER`.
+    pub pager: bool,
+    /// Include whether each code is IANA-registered or unofficial.
+    pub source: bool,
+    /// A search term to highlight in table descriptions, when colors are on.
+    pub highlight: Option<String>,
+    /// Wrap JSON output with per-class counts.
+    pub summary: bool,
+    /// Print JSON values as objects that repeat the code and name the class.
+    pub objects: bool,
+    /// Explain the row colors below tables. Ignored without colors.
+    pub legend: bool,
+    /// Prefix table descriptions with a class emoji. Ignored without colors.
+    pub emoji: bool,
+    /// The border style of tables.
+    pub border: Border,
+    /// The colors of tables, when colors are on.
+    pub theme: Theme,
+    /// The width to wrap tables and explanations at, if any.
+    pub wrap: Option<u16>,
+    /// List grouped classes from 5xx down to 1xx.
+    pub descending: bool,
+    /// Leave out the note on stderr when jq is missing.
+    #[cfg(feature = "external-jq")]
+    pub quiet: bool,
+}
+
+/// The terminal width, when writing to a terminal.
+pub fn terminal_width() -> Option<u16> {
+    Table::new().width()
+}
+
+/// Breaks `text` into lines of at most `width` characters at word boundaries.
+/// Words longer than the width are left on a line of their own.
+fn wrap_text(text: &str, width: usize) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    for word in text.split_whitespace() {
+        match lines.last_mut() {
+            Some(line) if line.chars().count() + 1 + word.chars().count() <= width => {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_string()),
+        }
+    }
+    lines.join("\n")
+}
+
+/// Builds the pager command from `$PAGER`, defaulting to `less`. Like git, it
+/// sets `LESS=FRX` when unset so short output and colors pass straight through.
+fn pager_command(pager: Option<&str>) -> Command {
+    let mut words = pager.unwrap_or_default().split_whitespace();
+    let mut command = Command::new(words.next().unwrap_or("less"));
+    command.args(words);
+    if env::var_os("LESS").is_none() {
+        command.env("LESS", "FRX");
+    }
+    command
+}
+
+/// Prints `text`, through the pager if requested and one can be started.
+fn show(out: &mut dyn Write, text: &str, pager: bool) -> io::Result<()> {
+    if pager {
+        let program = env::var("PAGER").ok();
+        if pipe_through(&mut pager_command(program.as_deref()), text) {
+            return Ok(());
+        }
+    }
+    write!(out, "{}", text)
+}
+
+/// The colors tables are painted with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Theme {
+    /// Header colors of the code, description, RFC, explanation and source
+    /// columns.
+    pub headers: [Color; 5],
+    /// Row colors of the 1xx to 5xx classes.
+    pub classes: [Color; 5],
+}
+
+impl Theme {
+    pub fn preset(name: ThemeName) -> Theme {
+        match name {
+            ThemeName::Default => Theme {
+                headers: [
+                    Color::Cyan,
+                    Color::Yellow,
+                    Color::Magenta,
+                    Color::Blue,
+                    Color::Green,
+                ],
+                classes: [
+                    Color::Blue,
+                    Color::Green,
+                    Color::Yellow,
+                    Color::Magenta,
+                    Color::Red,
+                ],
+            },
+            // Brighter rows for the classes that need attention.
+            ThemeName::Mono => Theme {
+                headers: [Color::White; 5],
+                classes: [
+                    Color::DarkGrey,
+                    Color::Grey,
+                    Color::Grey,
+                    Color::White,
+                    Color::White,
+                ],
+            },
+            // The accent colors of Ethan Schoonover's Solarized palette.
+            ThemeName::Solarized => {
+                let (yellow, orange, red) = (
+                    Color::Rgb {
+                        r: 0xb5,
+                        g: 0x89,
+                        b: 0x00,
+                    },
+                    Color::Rgb {
+                        r: 0xcb,
+                        g: 0x4b,
+                        b: 0x16,
+                    },
+                    Color::Rgb {
+                        r: 0xdc,
+                        g: 0x32,
+                        b: 0x2f,
+                    },
+                );
+                let (magenta, blue, cyan, green) = (
+                    Color::Rgb {
+                        r: 0xd3,
+                        g: 0x36,
+                        b: 0x82,
+                    },
+                    Color::Rgb {
+                        r: 0x26,
+                        g: 0x8b,
+                        b: 0xd2,
+                    },
+                    Color::Rgb {
+                        r: 0x2a,
+                        g: 0xa1,
+                        b: 0x98,
+                    },
+                    Color::Rgb {
+                        r: 0x85,
+                        g: 0x99,
+                        b: 0x00,
+                    },
+                );
+                Theme {
+                    headers: [cyan, yellow, magenta, blue, green],
+                    classes: [blue, green, yellow, orange, red],
+                }
+            }
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::preset(ThemeName::Default)
+    }
+}
+
+/// The comfy-table preset drawing a border style.
+fn border_preset(border: Border) -> &'static str {
+    match border {
+        Border::BordersOnly => UTF8_BORDERS_ONLY,
+        Border::Full => UTF8_FULL,
+        Border::Ascii => ASCII_FULL,
+        Border::AsciiBordersOnly => ASCII_BORDERS_ONLY,
+        Border::None => NOTHING,
+    }
+}
+
+/// The header text and header color of a table column.
+fn column_header(column: Column, theme: Theme) -> (&'static str, Color) {
+    let [code, description, rfc, explanation, source] = theme.headers;
+    match column {
+        Column::Code => ("Code", code),
+        Column::Description => ("Description", description),
+        Column::Rfc => ("RFC", rfc),
+        Column::Explanation => ("Explanation", explanation),
+        Column::Source => ("Source", source),
+    }
+}
+
+/// The color of a row, chosen by the code's class.
+fn class_color(code: u16, theme: Theme) -> Color {
+    match code / 100 {
+        digit @ 1..=5 => theme.classes[usize::from(digit - 1)],
+        _ => Color::Reset,
+    }
+}
+
+/// The text of a table cell, and the color it is painted with, if any.
+fn column_value(column: Column, status: &Status, theme: Theme) -> (String, Option<Color>) {
+    let color = class_color(status.code, theme);
+    match column {
+        Column::Code => (status.code.to_string(), Some(color)),
+        Column::Description if status.deprecated => {
+            (format!("{} (deprecated)", status.description), Some(color))
+        }
+        Column::Description => (status.description.to_string(), Some(color)),
+        Column::Rfc => (status.rfc.to_string(), None),
+        Column::Explanation => (status.explanation.to_string(), None),
+        Column::Source => (status.source.as_str().to_string(), None),
+    }
+}
+
+/// The emoji shown before descriptions with `--emoji`.
+fn class_emoji(code: u16) -> Option<&'static str> {
+    match code / 100 {
+        1 => Some("ℹ️"),
+        2 => Some("✅"),
+        3 => Some("↪️"),
+        4 => Some("⚠️"),
+        5 => Some("❌"),
+        _ => None,
+    }
+}
+
+/// Wraps each case-insensitive occurrence of `term` in bold, leaving the cell
+/// color untouched. Case is folded like [`matches`] does, so whatever the
+/// search found is highlighted, including non-ASCII letters.
+fn highlight(text: &str, term: &str) -> String {
+    if term.is_empty() || !matches(text, term) {
+        return text.to_string();
+    }
+    let needle = term.to_lowercase();
+    let mut highlighted = String::with_capacity(text.len());
+    let mut rest = 0;
+    let mut start = 0;
+    while let Some(c) = text[start..].chars().next() {
+        match folded_prefix_len(&text[start..], &needle) {
+            Some(len) => {
+                let end = start + len;
+                highlighted.push_str(&text[rest..start]);
+                highlighted.push_str(&format!("\x1b[1m{}\x1b[22m", &text[start..end]));
+                rest = end;
+                start = end;
+            }
+            None => start += c.len_utf8(),
+        }
+    }
+    highlighted.push_str(&text[rest..]);
+    highlighted
+}
+
+/// The length in bytes of the start of `text` that lowercases to `needle`,
+/// if there is one. Lowercasing can change a character's length, so offsets
+/// are taken from `text` itself rather than from a lowercased copy.
+fn folded_prefix_len(text: &str, needle: &str) -> Option<usize> {
+    let mut folded = String::new();
+    for (index, c) in text.char_indices() {
+        folded.extend(c.to_lowercase());
+        if folded == needle {
+            return Some(index + c.len_utf8());
+        }
+        if !needle.starts_with(&folded) {
+            return None;
+        }
+    }
+    None
+}
+
+/// One line naming each class in its row color.
+fn legend(theme: Theme) -> String {
+    let mut legend = Table::new();
+    legend.load_preset(NOTHING).enforce_styling();
+    legend.add_row(CLASSES.map(|digit| {
+        let name = class_name(digit * 100).unwrap_or_default();
+        paint(
+            Cell::new(format!("{}xx {}", digit, name)),
+            class_color(digit * 100, theme),
+            true,
+        )
+    }));
+    format!("{}\n", legend)
+}
+
+fn render_table(statuses: &[Status], options: &Options) -> String {
+    let (columns, color) = (&options.columns, options.color);
+    let mut table = Table::new();
+    table.load_preset(border_preset(options.border));
+    // comfy-table drops styling when stdout is not a terminal on its own; the
+    // caller has already decided, as with `--color always`.
+    if color {
+        table.enforce_styling();
+    }
+    if let Some(width) = options.wrap {
+        table
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_width(width);
+    }
+    table.set_header(columns.iter().map(|&column| {
+        let (name, header_color) = column_header(column, options.theme);
+        paint(Cell::new(name), header_color, color)
+    }));
+
+    for status in statuses {
+        table.add_row(columns.iter().map(|&column| {
+            match column_value(column, status, options.theme) {
+                (text, Some(cell_color)) => {
+                    let text = match &options.highlight {
+                        Some(term) if color && column == Column::Description => {
+                            highlight(&text, term)
+                        }
+                        _ => text,
+                    };
+                    let text = match class_emoji(status.code) {
+                        Some(emoji) if options.emoji && color && column == Column::Description => {
+                            format!("{} {}", emoji, text)
+                        }
+                        _ => text,
+                    };
+                    paint(Cell::new(text), cell_color, color)
+                }
+                (text, None) => Cell::new(text),
+            }
+        }));
+    }
+
+    table.to_string()
+}
+
+pub fn print_grouped(
+    out: &mut dyn Write,
+    statuses: &[Status],
+    options: &Options,
+) -> io::Result<()> {
+    let mut classes: Vec<u16> = count_by_class(statuses)
+        .into_iter()
+        .filter(|&(_, count)| count > 0)
+        .map(|(digit, _)| digit)
+        .collect();
+    if options.descending {
+        classes.reverse();
+    }
+    let mut text = String::new();
+    for digit in classes {
+        let group: Vec<Status> = statuses
+            .iter()
+            .filter(|status| status.code / 100 == digit)
+            .copied()
+            .collect();
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        match class_name(digit * 100) {
+            Some(name) => text.push_str(&format!("{}xx {}\n", digit, name)),
+            None => text.push_str(&format!("{}xx\n", digit)),
+        }
+        text.push_str(&render_table(&group, options));
+        text.push('\n');
+    }
+    if options.legend && options.color {
+        text.push('\n');
+        text.push_str(&legend(options.theme));
+    }
+    show(out, &text, options.pager)
+}
+
+/// Counts the codes in each class. The 1xx to 5xx classes are always present,
+/// even with no codes; any other class, such as 6xx from `--codes-file`, is
+/// present only when it has codes. Every per-class view is built on this.
+fn count_by_class(statuses: &[Status]) -> BTreeMap<u16, usize> {
+    let mut counts: BTreeMap<u16, usize> = CLASSES.iter().map(|&digit| (digit, 0)).collect();
+    for status in statuses {
+        *counts.entry(status.code / 100).or_default() += 1;
+    }
+    counts
+}
+
+/// Summarizes the statuses in one line, such as "63 HTTP status codes across
+/// 5 classes". Only classes with at least one code are counted.
+fn banner(statuses: &[Status]) -> String {
+    let classes = count_by_class(statuses)
+        .values()
+        .filter(|&&count| count > 0)
+        .count();
+    format!(
+        "{} HTTP status code{} across {} class{}",
+        statuses.len(),
+        if statuses.len() == 1 { "" } else { "s" },
+        classes,
+        if classes == 1 { "" } else { "es" }
+    )
+}
+
+pub fn print_banner(out: &mut dyn Write, statuses: &[Status]) -> io::Result<()> {
+    writeln!(out, "{}", banner(statuses))
+}
+
+pub fn print_counts(out: &mut dyn Write, statuses: &[Status]) -> io::Result<()> {
+    for (digit, count) in count_by_class(statuses) {
+        writeln!(out, "{}xx: {}", digit, count)?;
+    }
+    writeln!(out, "Total: {}", statuses.len())
+}
+
+/// Writes the per-class counts in the Prometheus text format. The 1xx to 5xx
+/// series are always present, so their label set does not change between
+/// scrapes; a class from `--codes-file` adds its own series.
+pub fn print_prometheus(out: &mut dyn Write, statuses: &[Status]) -> io::Result<()> {
+    writeln!(
+        out,
+        "# HELP http_status_codes_total Number of HTTP status codes in each class."
+    )?;
+    writeln!(out, "# TYPE http_status_codes_total gauge")?;
+    for (digit, count) in count_by_class(statuses) {
+        writeln!(
+            out,
+            "http_status_codes_total{{class=\"{}xx\"}} {}",
+            digit, count
+        )?;
+    }
+    Ok(())
+}
+
+/// Lines up `code description` rows with spaces instead of table borders, so
+/// the output copies cleanly and is easy to grep. Unofficial codes, which have
+/// no RFC, are marked `(unofficial)` instead.
+fn plain_lines(statuses: &[Status], rfc: bool) -> Vec<String> {
+    let width = statuses
+        .iter()
+        .map(|status| status.description.chars().count())
+        .max()
+        .unwrap_or(0);
+    statuses
+        .iter()
+        .map(|status| {
+            let note = if rfc && !status.rfc.is_empty() {
+                status.rfc
+            } else if status.source == Source::Unofficial {
+                "(unofficial)"
+            } else {
+                ""
+            };
+            if note.is_empty() {
+                format!("{}  {}", status.code, status.description)
+            } else {
+                format!("{}  {:<width$}  {}", status.code, status.description, note)
+            }
+        })
+        .collect()
+}
+
+fn print_plain(out: &mut dyn Write, statuses: &[Status], rfc: bool) -> io::Result<()> {
+    for line in plain_lines(statuses, rfc) {
+        writeln!(out, "{}", line)?;
+    }
+    Ok(())
+}
+
+fn print_only_codes(out: &mut dyn Write, statuses: &[Status]) -> io::Result<()> {
+    for status in statuses {
+        writeln!(out, "{}", status.code)?;
+    }
+    Ok(())
+}
+
+/// Quotes a CSV field per RFC 4180 when it contains a delimiter, quote or
+/// apostrophe, doubling any embedded quotes.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\'', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Writes a `code,description` header and rows, with a `source` column when
+/// `source` is set.
+fn print_csv(out: &mut dyn Write, statuses: &[Status], source: bool) -> io::Result<()> {
+    writeln!(
+        out,
+        "code,description{}",
+        if source { ",source" } else { "" }
+    )?;
+    for status in statuses {
+        write!(out, "{},{}", status.code, csv_field(status.description))?;
+        if source {
+            write!(out, ",{}", status.source.as_str())?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// Writes `code<TAB>description` lines without a header, for `cut` and `awk`,
+/// followed by the source when `source` is set. Built-in descriptions have no
+/// tabs or line breaks, and `--codes-file` rejects them, so nothing needs
+/// quoting.
+fn print_tsv(out: &mut dyn Write, statuses: &[Status], source: bool) -> io::Result<()> {
+    for status in statuses {
+        write!(out, "{}\t{}", status.code, status.description)?;
+        if source {
+            write!(out, "\t{}", status.source.as_str())?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// Escapes pipes so a value cannot split a Markdown table cell.
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+fn print_markdown(
+    out: &mut dyn Write,
+    statuses: &[Status],
+    rfc: bool,
+    source: bool,
+) -> io::Result<()> {
+    let mut headers = vec!["Code", "Description"];
+    headers.extend(rfc.then_some("RFC"));
+    headers.extend(source.then_some("Source"));
+    let rules: Vec<String> = headers.iter().map(|name| "-".repeat(name.len())).collect();
+    writeln!(out, "| {} |", headers.join(" | "))?;
+    writeln!(out, "| {} |", rules.join(" | "))?;
+    for status in statuses {
+        let mut cells = vec![status.code.to_string(), markdown_cell(status.description)];
+        cells.extend(rfc.then(|| markdown_cell(status.rfc)));
+        cells.extend(source.then(|| status.source.as_str().to_string()));
+        writeln!(out, "| {} |", cells.join(" | "))?;
+    }
+    Ok(())
+}
+
+/// Escapes text for use in HTML or XML element content and attribute values.
+fn html_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+const HTML_STYLE: &str = "\
+<style>
+  table.http-status { border-collapse: collapse; }
+  table.http-status th, table.http-status td { padding: 0.25em 0.75em; text-align: left; }
+  tr.status-1xx { color: #0969da; }
+  tr.status-2xx { color: #1a7f37; }
+  tr.status-3xx { color: #8250df; }
+  tr.status-4xx { color: #bf8700; }
+  tr.status-5xx { color: #cf222e; }
+</style>";
+
+fn print_html(out: &mut dyn Write, statuses: &[Status], rfc: bool, source: bool) -> io::Result<()> {
+    writeln!(out, "{}", HTML_STYLE)?;
+    writeln!(out, "<table class=\"http-status\">")?;
+    writeln!(out, "  <thead>")?;
+    write!(out, "    <tr><th>Code</th><th>Description</th>")?;
+    if rfc {
+        write!(out, "<th>RFC</th>")?;
+    }
+    if source {
+        write!(out, "<th>Source</th>")?;
+    }
+    writeln!(out, "</tr>")?;
+    writeln!(out, "  </thead>")?;
+    writeln!(out, "  <tbody>")?;
+    for status in statuses {
+        write!(
+            out,
+            "    <tr class=\"status-{}xx\"><td>{}</td><td>{}</td>",
+            status.code / 100,
+            status.code,
+            html_escape(status.description)
+        )?;
+        if rfc {
+            write!(out, "<td>{}</td>", html_escape(status.rfc))?;
+        }
+        if source {
+            write!(out, "<td>{}</td>", status.source.as_str())?;
+        }
+        writeln!(out, "</tr>")?;
+    }
+    writeln!(out, "  </tbody>")?;
+    writeln!(out, "</table>")
+}
+
+/// Renders `<statuses>` with one `<status code="404">Not Found</status>` per
+/// code, carrying `rfc` and `source` attributes when requested.
+fn xml_document(statuses: &[Status], rfc: bool, source: bool) -> String {
+    let mut document = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<statuses>\n");
+    for status in statuses {
+        document.push_str(&format!("  <status code=\"{}\"", status.code));
+        if rfc && !status.rfc.is_empty() {
+            document.push_str(&format!(" rfc=\"{}\"", html_escape(status.rfc)));
+        }
+        if source {
+            document.push_str(&format!(" source=\"{}\"", status.source.as_str()));
+        }
+        document.push_str(&format!(">{}</status>\n", html_escape(status.description)));
+    }
+    document.push_str("</statuses>\n");
+    document
+}
+
+fn print_xml(out: &mut dyn Write, statuses: &[Status], rfc: bool, source: bool) -> io::Result<()> {
+    write!(out, "{}", xml_document(statuses, rfc, source))
+}
+
+/// Writes the statuses to `out` in `format`.
+pub fn print(
+    out: &mut dyn Write,
+    format: Format,
+    statuses: &[Status],
+    options: &Options,
+) -> io::Result<()> {
+    let (rfc, source) = (options.rfc, options.source);
+    match format {
+        Format::Table => {
+            let mut text = format!("{}\n", render_table(statuses, options));
+            if options.legend && options.color {
+                text.push_str(&legend(options.theme));
+            }
+            show(out, &text, options.pager)?;
+        }
+        Format::Json => print_json(out, statuses, options)?,
+        Format::MinimalJson => print_minimal_json(out, statuses, options)?,
+        Format::Jsonl => print_jsonl(out, statuses, rfc, source)?,
+        Format::Csv => print_csv(out, statuses, source)?,
+        Format::Tsv => print_tsv(out, statuses, source)?,
+        Format::Yaml => print_yaml(out, statuses, rfc, source)?,
+        Format::Markdown => print_markdown(out, statuses, rfc, source)?,
+        Format::Html => print_html(out, statuses, rfc, source)?,
+        Format::Xml => print_xml(out, statuses, rfc, source)?,
+        Format::Toml => print_toml(out, statuses, rfc, source)?,
+        Format::Plain => print_plain(out, statuses, rfc)?,
+        Format::OnlyCodes => print_only_codes(out, statuses)?,
+    }
+    Ok(())
+}
+
+/// Writes the status line and the code's sample exchange, or a note when it
+/// has none yet.
+pub fn print_example(out: &mut dyn Write, status: &Status) -> io::Result<()> {
+    writeln!(out, "{}", status_line(status, true))?;
+    writeln!(out)?;
+    if status.example.is_empty() {
+        writeln!(out, "No example for this code yet.")
+    } else {
+        writeln!(out, "{}", status.example)
+    }
+}
+
+/// Formats `404 Not Found`, followed by the RFC in parentheses when requested
+/// and the code has one, or `(unofficial)` for vendor-specific codes.
+pub fn status_line(status: &Status, rfc: bool) -> String {
+    if rfc && !status.rfc.is_empty() {
+        format!("{} {} ({})", status.code, status.description, status.rfc)
+    } else if status.source == Source::Unofficial {
+        format!("{} {} (unofficial)", status.code, status.description)
+    } else {
+        format!("{} {}", status.code, status.description)
+    }
+}
+
+/// Prints the class name, such as `4xx Client Error`, and what the class
+/// means.
+pub fn print_class_info(out: &mut dyn Write, digit: u16, wrap: Option<u16>) -> io::Result<()> {
+    let code = digit * 100;
+    let summary = describe_class(code).unwrap_or_default();
+    writeln!(out, "{}xx {}", digit, class_name(code).unwrap_or_default())?;
+    writeln!(out)?;
+    match wrap {
+        Some(width) => writeln!(out, "{}", wrap_text(summary, width.into())),
+        None => writeln!(out, "{}", summary),
+    }
+}
+
+pub fn print_explanation(
+    out: &mut dyn Write,
+    status: &Status,
+    wrap: Option<u16>,
+) -> io::Result<()> {
+    writeln!(out, "{}", status_line(status, true))?;
+    writeln!(out)?;
+    match wrap {
+        Some(width) => writeln!(out, "{}", wrap_text(status.explanation, width.into()))?,
+        None => writeln!(out, "{}", status.explanation)?,
+    }
+    if !status.protocol_notes.is_empty() {
+        writeln!(out)?;
+        let notes = format!("Protocol notes: {}", status.protocol_notes);
+        match wrap {
+            Some(width) => writeln!(out, "{}", wrap_text(&notes, width.into()))?,
+            None => writeln!(out, "{}", notes)?,
+        }
+    }
+    if status.retryable {
+        writeln!(out)?;
+        let advice = format!("Retry: {}", status.retry_advice);
+        match wrap {
+            Some(width) => writeln!(out, "{}", wrap_text(&advice, width.into()))?,
+            None => writeln!(out, "{}", advice)?,
+        }
+    }
+    if !status.header_examples.is_empty() {
+        writeln!(out)?;
+        writeln!(out, "Header examples:")?;
+        for header in status.header_examples {
+            writeln!(out, "  {}", header)?;
+        }
+    }
+    if is_permanent_redirect(status.code) {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "Permanent redirect: clients and search engines should update links to the new URL."
+        )?;
+    } else if is_temporary_redirect(status.code) {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "Temporary redirect: clients should keep using the original URL."
+        )?;
+    }
+    if !status.related_methods.is_empty() {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "Related methods: {}",
+            status.related_methods.join(", ")
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Options, SCHEMA_DIALECT, Theme, banner, class_color, class_emoji, column_header,
+        column_value, count_by_class, csv_field, highlight, html_escape, json_document,
+        json_entries, json_object_entries, json_schema, json_value, jsonl_lines, legend,
+        markdown_cell, pager_command, pipe_through, plain_lines, print, print_csv, print_example,
+        print_explanation, print_grouped, print_minimal_json, print_only_codes, print_prometheus,
+        print_tsv, render_table, status_line, toml_document, wrap_text, xml_document,
+    };
+    use crate::cli::{Border, Column, Format, ThemeName};
+    use clap::ValueEnum;
+    use comfy_table::Color;
+    use httpstatus_rust::{
+        EXPECTED_CLASS_COUNTS, STATUS_CODE_COUNT, Status, status_codes, unofficial_status_codes,
+    };
+    use serde_json::{Value, json};
+    use std::collections::BTreeMap;
+    use std::process::Command;
+
+    fn all_statuses() -> Vec<Status> {
+        status_codes().values().copied().collect()
+    }
+
+    #[test]
+    fn test_json_output_is_valid() {
+        let json = serde_json::to_string(&json_entries(&all_statuses(), false, false));
+        assert!(json.is_ok(), "Should serialize to valid JSON");
+
+        let json_str = json.unwrap();
+        assert!(json_str.starts_with('{'));
+        assert!(json_str.ends_with('}'));
+        assert!(json_str.contains("\"100\""));
+        assert!(json_str.contains("\"500\""));
+    }
+
+    #[test]
+    fn test_json_document_round_trips() {
+        let options = Options {
+            rfc: true,
+            ..Options::default()
+        };
+        let json = json_document(&all_statuses(), &options).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["418"]["description"], "I'm a teapot");
+        assert_eq!(
+            parsed.as_object().map(|object| object.len()),
+            Some(STATUS_CODE_COUNT)
+        );
+    }
+
+    #[test]
+    fn test_jsonl_lines() {
+        let statuses: Vec<Status> = status_codes().range(404..=405).map(|(_, &s)| s).collect();
+        assert_eq!(
+            jsonl_lines(&statuses, false, false).unwrap(),
+            [
+                r#"{"code":404,"description":"Not Found"}"#,
+                r#"{"code":405,"description":"Method Not Allowed"}"#
+            ]
+        );
+        assert_eq!(
+            jsonl_lines(&statuses[..1], true, false).unwrap(),
+            [r#"{"code":404,"description":"Not Found","rfc":"RFC 9110"}"#]
+        );
+    }
+
+    #[test]
+    fn test_csv_field_quoting() {
+        assert_eq!(csv_field("Not Found"), "Not Found");
+        assert_eq!(csv_field("I'm a teapot"), "\"I'm a teapot\"");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_print_csv_quotes_the_teapot() {
+        let mut out = Vec::new();
+        print_csv(&mut out, &all_statuses(), false).unwrap();
+        let csv = String::from_utf8(out).unwrap();
+        let teapot: Vec<&str> = csv
+            .lines()
+            .filter(|line| line.starts_with("418,"))
+            .collect();
+        assert_eq!(teapot, ["418,\"I'm a teapot\""]);
+        assert_eq!(csv.lines().count(), STATUS_CODE_COUNT + 1);
+    }
+
+    #[test]
+    fn test_json_entries_with_rfc() {
+        let entries = json_entries(&all_statuses(), true, false);
+        assert_eq!(entries["404"]["description"], "Not Found");
+        assert_eq!(entries["404"]["rfc"], "RFC 9110");
+        assert_eq!(entries["425"]["rfc"], "RFC 8470");
+    }
+
+    #[test]
+    fn test_yaml_keys_are_strings() {
+        let yaml = serde_yaml::to_string(&json_entries(&all_statuses(), false, false)).unwrap();
+        assert!(yaml.starts_with("'100': Continue\n"));
+        assert!(yaml.contains("'418': I'm a teapot\n"));
+    }
+
+    #[test]
+    fn test_markdown_cell_escapes_pipes() {
+        assert_eq!(markdown_cell("Not Found"), "Not Found");
+        assert_eq!(markdown_cell("a|b"), "a\\|b");
+    }
+
+    #[test]
+    fn test_count_by_class() {
+        let counts = count_by_class(&all_statuses());
+        let expected: BTreeMap<u16, usize> = (1..=5).zip(EXPECTED_CLASS_COUNTS).collect();
+        assert_eq!(counts, expected);
+
+        let custom = Status {
+            code: 600,
+            ..status_codes()[&200]
+        };
+        let counts = count_by_class(&[status_codes()[&200], custom]);
+        let expected = BTreeMap::from([(1, 0), (2, 1), (3, 0), (4, 0), (5, 0), (6, 1)]);
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn test_print_grouped_includes_custom_classes() {
+        let custom = Status {
+            code: 600,
+            ..status_codes()[&200]
+        };
+        let options = Options {
+            descending: true,
+            ..Options::default()
+        };
+        let mut out = Vec::new();
+        print_grouped(&mut out, &[status_codes()[&200], custom], &options).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let headers: Vec<&str> = text
+            .lines()
+            .filter(|line| line.get(1..3) == Some("xx"))
+            .collect();
+        assert_eq!(headers, ["6xx", "2xx Success"]);
+    }
+
+    #[test]
+    fn test_banner() {
+        assert_eq!(
+            banner(&all_statuses()),
+            "63 HTTP status codes across 5 classes"
+        );
+        let client_errors: Vec<Status> = status_codes().range(400..500).map(|(_, &s)| s).collect();
+        assert_eq!(
+            banner(&client_errors),
+            "29 HTTP status codes across 1 class"
+        );
+        assert_eq!(
+            banner(&[status_codes()[&418]]),
+            "1 HTTP status code across 1 class"
+        );
+        assert_eq!(banner(&[]), "0 HTTP status codes across 0 classes");
+    }
+
+    #[test]
+    fn test_html_escape() {
+        assert_eq!(html_escape("I'm a teapot"), "I&#39;m a teapot");
+        assert_eq!(
+            html_escape("<a href=\"x\">&</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn test_toml_document_quotes_keys() {
+        let document = toml_document(&all_statuses(), false, false);
+        assert!(document.starts_with("[codes]\n\"100\" = \"Continue\"\n"));
+        assert!(document.contains("\"418\" = \"I'm a teapot\"\n"));
+
+        let parsed: toml::Table = toml::from_str(&document).unwrap();
+        assert_eq!(parsed["codes"]["404"].as_str(), Some("Not Found"));
+    }
+
+    #[test]
+    fn test_toml_document_with_rfc() {
+        let parsed: toml::Table =
+            toml::from_str(&toml_document(&all_statuses(), true, false)).unwrap();
+        assert_eq!(
+            parsed["codes"]["425"]["description"].as_str(),
+            Some("Too Early")
+        );
+        assert_eq!(parsed["codes"]["425"]["rfc"].as_str(), Some("RFC 8470"));
+    }
+
+    #[test]
+    fn test_plain_lines() {
+        let codes: Vec<Status> = status_codes().range(200..=202).map(|(_, &s)| s).collect();
+        assert_eq!(
+            plain_lines(&codes, false),
+            ["200  OK", "201  Created", "202  Accepted"]
+        );
+        assert_eq!(
+            plain_lines(&codes, true),
+            [
+                "200  OK        RFC 9110",
+                "201  Created   RFC 9110",
+                "202  Accepted  RFC 9110"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pipe_through_reports_missing_program() {
+        let mut command = Command::new("httpstatus-test-no-such-jq");
+        assert!(!pipe_through(&mut command, "{}"));
+    }
+
+    #[test]
+    fn test_pager_command() {
+        let command = pager_command(None);
+        assert_eq!(command.get_program(), "less");
+
+        let command = pager_command(Some("less -S"));
+        assert_eq!(command.get_program(), "less");
+        assert_eq!(command.get_args().collect::<Vec<_>>(), ["-S"]);
+    }
+
+    #[test]
+    fn test_json_entries_keep_row_order() {
+        let statuses = [status_codes()[&404], status_codes()[&200]];
+        let json = serde_json::to_string(&json_entries(&statuses, false, false)).unwrap();
+        assert_eq!(json, r#"{"404":"Not Found","200":"OK"}"#);
+    }
+
+    #[test]
+    fn test_class_color() {
+        let theme = Theme::default();
+        assert_eq!(class_color(100, theme), Color::Blue);
+        assert_eq!(class_color(204, theme), Color::Green);
+        assert_eq!(class_color(301, theme), Color::Yellow);
+        assert_eq!(class_color(404, theme), Color::Magenta);
+        assert_eq!(class_color(503, theme), Color::Red);
+        assert_eq!(class_color(999, theme), Color::Reset);
+    }
+
+    #[test]
+    fn test_ascii_border_uses_only_ascii() {
+        let options = Options {
+            columns: vec![Column::Code, Column::Description],
+            border: Border::Ascii,
+            ..Options::default()
+        };
+        let table = render_table(&all_statuses(), &options);
+        assert!(table.is_ascii());
+        assert!(table.starts_with("+-"));
+    }
+
+    #[test]
+    fn test_render_table_colors_only_when_asked() {
+        let mut options = Options {
+            columns: vec![Column::Code, Column::Description],
+            ..Options::default()
+        };
+        assert!(!render_table(&all_statuses(), &options).contains('\x1b'));
+        options.color = true;
+        assert!(render_table(&all_statuses(), &options).contains("\x1b["));
+    }
+
+    #[test]
+    fn test_theme_presets() {
+        let mono = Theme::preset(ThemeName::Mono);
+        assert_eq!(class_color(503, mono), Color::White);
+        assert_eq!(column_header(Column::Code, mono), ("Code", Color::White));
+
+        let solarized = Theme::preset(ThemeName::Solarized);
+        assert_ne!(solarized, Theme::default());
+        assert_eq!(
+            class_color(200, solarized),
+            Color::Rgb {
+                r: 0x85,
+                g: 0x99,
+                b: 0x00
+            }
+        );
+    }
+
+    #[test]
+    fn test_xml_document_parses() {
+        let document = xml_document(&all_statuses(), true, false);
+        let parsed = roxmltree::Document::parse(&document).unwrap();
+        let root = parsed.root_element();
+        assert_eq!(root.tag_name().name(), "statuses");
+
+        let teapot = root
+            .children()
+            .find(|node| node.attribute("code") == Some("418"))
+            .unwrap();
+        assert_eq!(teapot.text(), Some("I'm a teapot"));
+        assert_eq!(teapot.attribute("rfc"), Some("RFC 2324"));
+    }
+
+    #[test]
+    fn test_deprecated_codes_are_marked_in_tables() {
+        let (text, _) = column_value(Column::Description, &status_codes()[&305], Theme::default());
+        assert_eq!(text, "Use Proxy (deprecated)");
+        let (text, _) = column_value(Column::Description, &status_codes()[&307], Theme::default());
+        assert_eq!(text, "Temporary Redirect");
+    }
+
+    #[test]
+    fn test_highlight() {
+        assert_eq!(
+            highlight("Bad Gateway", "gate"),
+            "Bad \x1b[1mGate\x1b[22mway"
+        );
+        assert_eq!(
+            highlight("Not Not", "not"),
+            "\x1b[1mNot\x1b[22m \x1b[1mNot\x1b[22m"
+        );
+        assert_eq!(highlight("OK", "zzz"), "OK");
+        assert_eq!(highlight("OK", ""), "OK");
+    }
+
+    #[test]
+    fn test_highlight_folds_non_ascii_case() {
+        assert_eq!(
+            highlight("Zeitüberschreitung der Anfrage", "ÜBER"),
+            "Zeit\x1b[1müber\x1b[22mschreitung der Anfrage"
+        );
+        assert_eq!(
+            highlight("Nutzlast zu groß", "GROß"),
+            "Nutzlast zu \x1b[1mgroß\x1b[22m"
+        );
+        // 'İ' lowercases to two characters, so the offsets differ from a
+        // lowercased copy of the text.
+        assert_eq!(highlight("İ OK", "ok"), "İ \x1b[1mOK\x1b[22m");
+    }
+
+    #[test]
+    fn test_print_only_codes() {
+        let statuses: Vec<Status> = status_codes().range(100..200).map(|(_, &s)| s).collect();
+        let mut out = Vec::new();
+        print_only_codes(&mut out, &statuses).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "100\n101\n102\n103\n");
+    }
+
+    #[test]
+    fn test_source_marks_unofficial_codes() {
+        let statuses = [status_codes()[&500], unofficial_status_codes()[&499]];
+        let entries = json_entries(&statuses, false, true);
+        assert_eq!(entries["500"]["source"], "iana");
+        assert_eq!(entries["499"]["source"], "unofficial");
+
+        let mut out = Vec::new();
+        print_csv(&mut out, &statuses, true).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "code,description,source\n500,Internal Server Error,iana\n499,Client Closed Request,unofficial\n"
+        );
+
+        assert_eq!(
+            status_line(&statuses[1], false),
+            "499 Client Closed Request (unofficial)"
+        );
+        assert_eq!(
+            plain_lines(&statuses, false)[1],
+            "499  Client Closed Request  (unofficial)"
+        );
+    }
+
+    #[test]
+    fn test_print_explanation_lists_related_methods() {
+        let mut out = Vec::new();
+        print_explanation(&mut out, &status_codes()[&413], None).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.starts_with("413 Payload Too Large (RFC 9110)\n\n"));
+        assert!(text.ends_with("\n\nRelated methods: POST, PUT, PATCH\n"));
+
+        let mut out = Vec::new();
+        print_explanation(&mut out, &status_codes()[&404], None).unwrap();
+        assert!(!String::from_utf8(out).unwrap().contains("Related methods"));
+    }
+
+    #[test]
+    fn test_print_writes_every_format_to_the_buffer() {
+        let statuses = [status_codes()[&404]];
+        let options = Options {
+            columns: vec![Column::Code, Column::Description],
+            ..Options::default()
+        };
+        for &format in Format::value_variants() {
+            let mut out = Vec::new();
+            print(&mut out, format, &statuses, &options).unwrap();
+            let text = String::from_utf8(out).unwrap();
+            assert!(text.contains("404"), "{:?} output: {}", format, text);
+            if format != Format::OnlyCodes {
+                assert!(text.contains("Not Found"), "{:?} output: {}", format, text);
+            }
+        }
+    }
+
+    #[test]
+    fn test_print_explanation_includes_protocol_notes() {
+        let mut out = Vec::new();
+        print_explanation(&mut out, &status_codes()[&421], None).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("\n\nProtocol notes: Mostly seen with HTTP/2 and HTTP/3"));
+
+        let mut out = Vec::new();
+        print_explanation(&mut out, &status_codes()[&404], None).unwrap();
+        assert!(!String::from_utf8(out).unwrap().contains("Protocol notes"));
+    }
+
+    #[test]
+    fn test_print_explanation_gives_retry_advice() {
+        let mut out = Vec::new();
+        print_explanation(&mut out, &status_codes()[&503], None).unwrap();
+        assert!(
+            String::from_utf8(out)
+                .unwrap()
+                .contains("\n\nRetry: Wait for the delay in `Retry-After`")
+        );
+
+        let mut out = Vec::new();
+        print_explanation(&mut out, &status_codes()[&429], None).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains(
+            "\n\nHeader examples:\n  Retry-After: 30\n  Retry-After: Wed, 21 Oct 2026 07:28:00 GMT\n"
+        ));
+
+        let mut out = Vec::new();
+        print_explanation(&mut out, &status_codes()[&404], None).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(!text.contains("Retry:"));
+        assert!(!text.contains("Header examples"));
+    }
+
+    #[test]
+    fn test_print_explanation_notes_redirect_permanence() {
+        let explain = |code| {
+            let mut out = Vec::new();
+            print_explanation(&mut out, &status_codes()[&code], None).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert!(explain(301).contains("\n\nPermanent redirect: "));
+        assert!(explain(307).contains("\n\nTemporary redirect: "));
+        assert!(!explain(304).contains(" redirect: "));
+    }
+
+    /// Checks `value` against the few schema keywords `json_schema` uses.
+    /// Name patterns are checked by shape: three digits, or a digit and "xx".
+    fn conforms(value: &Value, schema: &Value) -> bool {
+        if let Some(allowed) = schema["enum"].as_array() {
+            return allowed.contains(value);
+        }
+        match schema["type"].as_str() {
+            Some("string") => value.is_string(),
+            Some("integer") => value.as_u64().is_some(),
+            Some("object") => {
+                let Some(object) = value.as_object() else {
+                    return false;
+                };
+                let named = |name: &str| match schema["propertyNames"]["pattern"].as_str() {
+                    Some("^[1-9][0-9]{2}$") => {
+                        name.len() == 3 && name.bytes().all(|b| b.is_ascii_digit())
+                    }
+                    Some("^[1-9]xx$") => name.len() == 3 && name.ends_with("xx"),
+                    _ => true,
+                };
+                let required = schema["required"].as_array().into_iter().flatten();
+                required
+                    .into_iter()
+                    .all(|name| object.contains_key(name.as_str().unwrap()))
+                    && object.iter().all(|(name, item)| {
+                        let item_schema = match schema["properties"].get(name) {
+                            Some(item_schema) => item_schema,
+                            None => &schema["additionalProperties"],
+                        };
+                        named(name)
+                            && item_schema != &json!(false)
+                            && (item_schema.is_null() || conforms(item, item_schema))
+                    })
+            }
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_json_output_matches_schema() {
+        let mut statuses = all_statuses();
+        statuses.extend(unofficial_status_codes().values());
+        for (rfc, source, summary, objects) in [
+            (false, false, false, false),
+            (true, false, false, false),
+            (false, true, false, false),
+            (true, true, true, false),
+            (false, false, true, false),
+            (false, false, false, true),
+            (true, true, true, true),
+        ] {
+            let options = Options {
+                rfc,
+                source,
+                summary,
+                objects,
+                ..Options::default()
+            };
+            let schema = json_schema(&options);
+            assert_eq!(schema["$schema"], SCHEMA_DIALECT);
+            assert!(conforms(&json_value(&statuses, &options), &schema));
+            let other = Options {
+                summary: !summary,
+                ..options
+            };
+            assert!(!conforms(&json_value(&statuses, &other), &schema));
+        }
+    }
+
+    #[test]
+    fn test_json_object_entries() {
+        let entries = json_object_entries(&[status_codes()[&404]], false, false);
+        assert_eq!(
+            entries["404"],
+            json!({ "code": 404, "description": "Not Found", "class": "4xx" })
+        );
+        let entries = json_object_entries(&[unofficial_status_codes()[&499]], true, true);
+        assert_eq!(entries["499"]["source"], "unofficial");
+        assert_eq!(entries["499"]["class"], "4xx");
+    }
+
+    #[test]
+    fn test_print_example() {
+        let mut out = Vec::new();
+        print_example(&mut out, &status_codes()[&404]).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.starts_with("404 Not Found (RFC 9110)\n\nGET /"));
+        assert!(text.contains("\n\nHTTP/1.1 404 Not Found\n"));
+
+        let mut out = Vec::new();
+        print_example(&mut out, &status_codes()[&418]).unwrap();
+        assert!(
+            String::from_utf8(out)
+                .unwrap()
+                .ends_with("\n\nNo example for this code yet.\n")
+        );
+    }
+
+    #[test]
+    fn test_emoji_prefixes_descriptions_only_with_colors() {
+        let statuses = [status_codes()[&200], status_codes()[&503]];
+        let mut options = Options {
+            columns: vec![Column::Code, Column::Description],
+            emoji: true,
+            color: true,
+            ..Options::default()
+        };
+        let table = render_table(&statuses, &options);
+        assert!(table.contains("✅ "));
+        assert!(table.contains("❌ "));
+        assert!(!table.contains("⚠️"));
+
+        options.color = false;
+        assert!(!render_table(&statuses, &options).contains("✅"));
+        assert!(
+            (100..600)
+                .step_by(100)
+                .all(|code| class_emoji(code).is_some())
+        );
+        assert_eq!(class_emoji(600), None);
+    }
+
+    #[test]
+    fn test_print_tsv() {
+        let statuses: Vec<Status> = status_codes().range(404..=405).map(|(_, &s)| s).collect();
+        let mut out = Vec::new();
+        print_tsv(&mut out, &statuses, false).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "404\tNot Found\n405\tMethod Not Allowed\n"
+        );
+        assert!(all_statuses().iter().all(|s| !s.description.contains('\t')));
+    }
+
+    #[test]
+    fn test_print_prometheus() {
+        let mut out = Vec::new();
+        print_prometheus(&mut out, &all_statuses()).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let samples: Vec<&str> = text.lines().filter(|line| !line.starts_with('#')).collect();
+        let expected: Vec<String> = (1..=5)
+            .zip(EXPECTED_CLASS_COUNTS)
+            .map(|(digit, count)| {
+                format!("http_status_codes_total{{class=\"{}xx\"}} {}", digit, count)
+            })
+            .collect();
+        assert_eq!(samples, expected);
+    }
+
+    #[test]
+    fn test_print_minimal_json() {
+        let statuses: Vec<Status> = status_codes().range(200..=202).map(|(_, &s)| s).collect();
+        let mut out = Vec::new();
+        print_minimal_json(&mut out, &statuses, &Options::default()).unwrap();
+        assert_eq!(
+            out,
+            b"{\"200\":\"OK\",\"201\":\"Created\",\"202\":\"Accepted\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_json_value_with_summary() {
+        let value = json_value(
+            &all_statuses(),
+            &Options {
+                summary: true,
+                ..Options::default()
+            },
+        );
+        assert_eq!(value["codes"]["404"], "Not Found");
+        assert_eq!(value["summary"]["4xx"], 29);
+        let classes: Vec<&String> = value["summary"].as_object().unwrap().keys().collect();
+        assert_eq!(classes, ["1xx", "2xx", "3xx", "4xx", "5xx"]);
+
+        assert_eq!(
+            json_value(&all_statuses(), &Options::default())["404"],
+            "Not Found"
+        );
+    }
+
+    #[test]
+    fn test_legend_names_every_class() {
+        let legend = legend(Theme::default());
+        for name in ["1xx Informational", "3xx Redirection", "5xx Server Error"] {
+            assert!(legend.contains(name), "Legend should mention {}", name);
+        }
+        assert_eq!(legend.lines().count(), 1);
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        assert_eq!(wrap_text("one two three four", 9), "one two\nthree\nfour");
+        assert_eq!(wrap_text("unbreakable word", 5), "unbreakable\nword");
+    }
+
+    #[test]
+    fn test_render_table_wraps_to_width() {
+        let options = Options {
+            columns: vec![Column::Code, Column::Explanation],
+            wrap: Some(40),
+            ..Options::default()
+        };
+        let table = render_table(&all_statuses(), &options);
+        assert!(table.lines().all(|line| line.chars().count() <= 40));
+    }
+}
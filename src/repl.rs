@@ -0,0 +1,97 @@
+use crate::{output, select, suggest, suggestion_hint};
+use httpstatus_rust::{Status, alias_code};
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, Write};
+
+const PROMPT: &str = "httpstatus> ";
+
+/// Reads codes or search terms until EOF or `quit`, answering each one.
+pub fn run(status_codes: &BTreeMap<u16, Status>, rfc: bool) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        write!(stdout, "{}", PROMPT)?;
+        stdout.flush()?;
+
+        let Some(line) = lines.next().transpose()? else {
+            writeln!(stdout)?;
+            return Ok(());
+        };
+        let input = line.trim();
+        match input {
+            "" => continue,
+            "quit" | "exit" => return Ok(()),
+            _ => writeln!(stdout, "{}", respond(input, status_codes, rfc))?,
+        }
+    }
+}
+
+/// Answers one line of input: a code or informal name is looked up, anything
+/// else is searched for in the descriptions.
+fn respond(input: &str, status_codes: &BTreeMap<u16, Status>, rfc: bool) -> String {
+    if let Some(code) = input.parse::<u16>().ok().or_else(|| alias_code(input)) {
+        return match status_codes.get(&code) {
+            Some(status) => output::status_line(status, rfc),
+            None => with_hint(
+                format!("Unknown status code: {}", code),
+                &suggest::similar_codes(code, status_codes),
+            ),
+        };
+    }
+
+    let matches = select(status_codes, None, Some(input), false);
+    if matches.is_empty() {
+        return with_hint(
+            format!("No status codes match '{}'", input),
+            &suggest::similar_descriptions(input, status_codes),
+        );
+    }
+    matches
+        .values()
+        .map(|status| output::status_line(status, rfc))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn with_hint(message: String, suggestions: &[Status]) -> String {
+    match suggestion_hint(suggestions) {
+        Some(hint) => format!("{}\n{}", message, hint),
+        None => message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::respond;
+    use httpstatus_rust::status_codes;
+
+    #[test]
+    fn test_respond_to_code() {
+        assert_eq!(respond("404", status_codes(), false), "404 Not Found");
+        assert_eq!(
+            respond("425", status_codes(), true),
+            "425 Too Early (RFC 8470)"
+        );
+        assert!(
+            respond("4004", status_codes(), false)
+                .starts_with("Unknown status code: 4004\nDid you mean")
+        );
+    }
+
+    #[test]
+    fn test_respond_to_alias() {
+        assert_eq!(respond("teapot", status_codes(), false), "418 I'm a teapot");
+    }
+
+    #[test]
+    fn test_respond_to_search() {
+        assert_eq!(
+            respond("gateway", status_codes(), false),
+            "502 Bad Gateway\n504 Gateway Timeout"
+        );
+        assert_eq!(
+            respond("zzzz", status_codes(), false),
+            "No status codes match 'zzzz'"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::codes::description;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A registered status code together with its reason phrase. It can only be
+/// built from a code in [`status_codes`](crate::status_codes).
+///
+/// ```
+/// use httpstatus_rust::StatusCode;
+///
+/// let status: StatusCode = "404".parse().unwrap();
+/// assert_eq!(status.code(), 404);
+/// assert_eq!(status.to_string(), "404 Not Found");
+/// assert!(StatusCode::try_from(999).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StatusCode {
+    code: u16,
+    description: &'static str,
+}
+
+impl StatusCode {
+    pub fn code(self) -> u16 {
+        self.code
+    }
+
+    pub fn description(self) -> &'static str {
+        self.description
+    }
+}
+
+/// Why a value could not be turned into a [`StatusCode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidStatusCode {
+    /// The text is not a number.
+    NotANumber,
+    /// The number is not a registered status code.
+    Unregistered(u16),
+}
+
+impl fmt::Display for InvalidStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidStatusCode::NotANumber => write!(f, "not a status code"),
+            InvalidStatusCode::Unregistered(code) => write!(f, "unknown status code: {}", code),
+        }
+    }
+}
+
+impl Error for InvalidStatusCode {}
+
+impl TryFrom<u16> for StatusCode {
+    type Error = InvalidStatusCode;
+
+    fn try_from(code: u16) -> Result<Self, Self::Error> {
+        description(code)
+            .map(|description| StatusCode { code, description })
+            .ok_or(InvalidStatusCode::Unregistered(code))
+    }
+}
+
+impl FromStr for StatusCode {
+    type Err = InvalidStatusCode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code: u16 = s
+            .trim()
+            .parse()
+            .map_err(|_| InvalidStatusCode::NotANumber)?;
+        StatusCode::try_from(code)
+    }
+}
+
+impl From<StatusCode> for u16 {
+    fn from(status: StatusCode) -> u16 {
+        status.code
+    }
+}
+
+#[cfg(feature = "http")]
+impl TryFrom<http::StatusCode> for StatusCode {
+    type Error = InvalidStatusCode;
+
+    fn try_from(status: http::StatusCode) -> Result<Self, Self::Error> {
+        StatusCode::try_from(status.as_u16())
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<StatusCode> for http::StatusCode {
+    fn from(status: StatusCode) -> http::StatusCode {
+        // Every registered code lies in 100..=599, which `http` accepts.
+        http::StatusCode::from_u16(status.code).expect("registered codes are valid")
+    }
+}
+
+/// This crate's description of an [`http::StatusCode`], or `None` when the
+/// code is not registered. Requires the `http` feature.
+///
+/// ```
+/// # #[cfg(feature = "http")]
+/// assert_eq!(
+///     httpstatus_rust::canonical_reason(http::StatusCode::IM_A_TEAPOT),
+///     Some("I'm a teapot")
+/// );
+/// ```
+#[cfg(feature = "http")]
+pub fn canonical_reason(status: http::StatusCode) -> Option<&'static str> {
+    description(status.as_u16())
+}
+
+impl fmt::Display for StatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.code, self.description)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidStatusCode, StatusCode};
+
+    #[test]
+    fn test_try_from() {
+        let status = StatusCode::try_from(503).unwrap();
+        assert_eq!(status.code(), 503);
+        assert_eq!(status.description(), "Service Unavailable");
+        assert_eq!(u16::from(status), 503);
+        assert_eq!(
+            StatusCode::try_from(520),
+            Err(InvalidStatusCode::Unregistered(520))
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "418".parse::<StatusCode>().unwrap().description(),
+            "I'm a teapot"
+        );
+        assert_eq!(" 200 ".parse::<StatusCode>().unwrap().code(), 200);
+        assert_eq!(
+            "abc".parse::<StatusCode>(),
+            Err(InvalidStatusCode::NotANumber)
+        );
+        assert_eq!(
+            "999".parse::<StatusCode>(),
+            Err(InvalidStatusCode::Unregistered(999))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            StatusCode::try_from(404).unwrap().to_string(),
+            "404 Not Found"
+        );
+        assert_eq!(
+            InvalidStatusCode::Unregistered(999).to_string(),
+            "unknown status code: 999"
+        );
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_http_conversions() {
+        use super::canonical_reason;
+
+        let status = StatusCode::try_from(http::StatusCode::NOT_FOUND).unwrap();
+        assert_eq!(status.description(), "Not Found");
+        assert_eq!(http::StatusCode::from(status), http::StatusCode::NOT_FOUND);
+        assert_eq!(
+            StatusCode::try_from(http::StatusCode::from_u16(599).unwrap()),
+            Err(InvalidStatusCode::Unregistered(599))
+        );
+        assert_eq!(canonical_reason(http::StatusCode::OK), Some("OK"));
+    }
+}
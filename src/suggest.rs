@@ -0,0 +1,138 @@
+use httpstatus_rust::Status;
+use std::collections::BTreeMap;
+
+/// How many suggestions to offer at most.
+const MAX_SUGGESTIONS: usize = 2;
+
+/// Computes the Levenshtein edit distance between two strings.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Suggests codes whose digits are closest to an unknown code, e.g. 404 for
+/// 4004. Ties go to the numerically nearest code.
+pub fn similar_codes(code: u16, status_codes: &BTreeMap<u16, Status>) -> Vec<Status> {
+    let digits = code.to_string();
+    closest(status_codes, 1, |status| {
+        let distance = levenshtein(&digits, &status.code.to_string());
+        (distance, status.code.abs_diff(code))
+    })
+}
+
+/// Finds the known codes immediately below and above an unknown code, e.g.
+/// 431 and 451 for 450.
+pub fn nearest_codes(code: u16, status_codes: &BTreeMap<u16, Status>) -> Vec<u16> {
+    let below = status_codes.range(..code).next_back();
+    let above = status_codes.range(code.saturating_add(1)..).next();
+    below
+        .into_iter()
+        .chain(above)
+        .map(|(&code, _)| code)
+        .collect()
+}
+
+/// Suggests codes whose description nearly matches a search term, comparing
+/// the term against every run of as many words in the description.
+pub fn similar_descriptions(term: &str, status_codes: &BTreeMap<u16, Status>) -> Vec<Status> {
+    let term = term.to_lowercase();
+    let term_words = term.split_whitespace().count().max(1);
+    let max_distance = (term.chars().count() / 3).max(1);
+    closest(status_codes, max_distance, |status| {
+        let description = status.description.to_lowercase();
+        let words: Vec<&str> = description.split_whitespace().collect();
+        let distance = words
+            .windows(term_words.min(words.len()).max(1))
+            .map(|window| levenshtein(&term, &window.join(" ")))
+            .min()
+            .unwrap_or(usize::MAX);
+        (distance, status.code)
+    })
+}
+
+/// Returns the best candidates within `max_distance`, ordered by the key
+/// returned from `rank`, whose first element is the edit distance.
+fn closest<K: Ord>(
+    status_codes: &BTreeMap<u16, Status>,
+    max_distance: usize,
+    rank: impl Fn(&Status) -> (usize, K),
+) -> Vec<Status> {
+    let mut candidates: Vec<((usize, K), Status)> = status_codes
+        .values()
+        .map(|status| (rank(status), *status))
+        .filter(|((distance, _), _)| *distance <= max_distance)
+        .collect();
+    candidates.sort_by(|(a, _), (b, _)| a.cmp(b));
+    candidates
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, status)| status)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{levenshtein, nearest_codes, similar_codes, similar_descriptions};
+    use httpstatus_rust::status_codes;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("abc", ""), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("teapot", "teapot"), 0);
+        assert_eq!(levenshtein("gatway", "gateway"), 1);
+    }
+
+    #[test]
+    fn test_similar_codes() {
+        let codes: Vec<u16> = similar_codes(4004, status_codes())
+            .iter()
+            .map(|status| status.code)
+            .collect();
+        assert_eq!(codes, [404, 400]);
+
+        let codes: Vec<u16> = similar_codes(450, status_codes())
+            .iter()
+            .map(|status| status.code)
+            .collect();
+        assert_eq!(codes, [451, 410]);
+        assert!(similar_codes(9999, status_codes()).is_empty());
+    }
+
+    #[test]
+    fn test_similar_descriptions() {
+        let codes: Vec<u16> = similar_descriptions("gatway", status_codes())
+            .iter()
+            .map(|status| status.code)
+            .collect();
+        assert_eq!(codes, [502, 504]);
+
+        let teapot = similar_descriptions("teapt", status_codes());
+        assert_eq!(teapot.first().map(|status| status.code), Some(418));
+
+        let too_many = similar_descriptions("too manny", status_codes());
+        assert_eq!(too_many.first().map(|status| status.code), Some(429));
+        assert!(similar_descriptions("zzzzzz", status_codes()).is_empty());
+    }
+
+    #[test]
+    fn test_nearest_codes() {
+        assert_eq!(nearest_codes(450, status_codes()), [431, 451]);
+        assert_eq!(nearest_codes(99, status_codes()), [100]);
+        assert_eq!(nearest_codes(700, status_codes()), [511]);
+    }
+}
@@ -0,0 +1,127 @@
+//! Invariants of the status code data, shared by the tests and the
+//! `--selfcheck` mode of the binary.
+
+use crate::codes::{Source, Status};
+
+/// How many IANA-registered codes each class from 1xx to 5xx has.
+pub const EXPECTED_CLASS_COUNTS: [usize; 5] = [4, 10, 9, 29, 11];
+
+/// Checks that the codes are strictly ascending, which also rules out
+/// duplicates.
+pub(crate) fn check_ascending(statuses: &[Status]) -> Result<(), String> {
+    match statuses
+        .windows(2)
+        .find(|pair| pair[0].code >= pair[1].code)
+    {
+        Some(pair) => Err(format!("{} comes after {}", pair[1].code, pair[0].code)),
+        None => Ok(()),
+    }
+}
+
+/// Checks that every code has three digits.
+pub(crate) fn check_three_digits(statuses: &[Status]) -> Result<(), String> {
+    match statuses
+        .iter()
+        .find(|status| !(100..=999).contains(&status.code))
+    {
+        Some(status) => Err(format!("{} does not have three digits", status.code)),
+        None => Ok(()),
+    }
+}
+
+/// Checks that no description is empty or only whitespace.
+pub(crate) fn check_descriptions(statuses: &[Status]) -> Result<(), String> {
+    match statuses
+        .iter()
+        .find(|status| status.description.trim().is_empty())
+    {
+        Some(status) => Err(format!("{} has an empty description", status.code)),
+        None => Ok(()),
+    }
+}
+
+/// Checks that the IANA-registered codes among `statuses` match
+/// [`EXPECTED_CLASS_COUNTS`]. Unofficial codes are not counted.
+pub(crate) fn check_class_counts(statuses: &[Status]) -> Result<(), String> {
+    for (digit, &expected) in (1..=5u16).zip(&EXPECTED_CLASS_COUNTS) {
+        let count = statuses
+            .iter()
+            .filter(|status| status.source == Source::Iana && status.code / 100 == digit)
+            .count();
+        if count != expected {
+            return Err(format!(
+                "{}xx has {} registered codes, expected {}",
+                digit, count, expected
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Runs every check, returning each one's name and outcome in order.
+///
+/// ```
+/// let statuses: Vec<_> = httpstatus_rust::status_codes().values().copied().collect();
+/// assert!(httpstatus_rust::self_check(&statuses).iter().all(|(_, result)| result.is_ok()));
+/// ```
+pub fn self_check(statuses: &[Status]) -> Vec<(&'static str, Result<(), String>)> {
+    vec![
+        ("codes in ascending order", check_ascending(statuses)),
+        ("three-digit codes", check_three_digits(statuses)),
+        ("non-empty descriptions", check_descriptions(statuses)),
+        ("per-class counts", check_class_counts(statuses)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        check_ascending, check_class_counts, check_descriptions, check_three_digits, self_check,
+    };
+    use crate::codes::{Status, status_codes, unofficial_status_codes};
+
+    fn all_statuses() -> Vec<Status> {
+        status_codes().values().copied().collect()
+    }
+
+    #[test]
+    fn test_built_in_data_passes() {
+        for (name, result) in self_check(&all_statuses()) {
+            assert_eq!(result, Ok(()), "{}", name);
+        }
+        let mut extended = all_statuses();
+        extended.extend(unofficial_status_codes().values());
+        extended.sort_by_key(|status| status.code);
+        assert!(
+            self_check(&extended)
+                .iter()
+                .all(|(_, result)| result.is_ok())
+        );
+    }
+
+    #[test]
+    fn test_checks_report_problems() {
+        let mut statuses = all_statuses();
+        statuses.swap(0, 1);
+        assert_eq!(
+            check_ascending(&statuses),
+            Err("100 comes after 101".to_string())
+        );
+
+        let mut statuses = all_statuses();
+        statuses[0].description = " ";
+        assert!(check_descriptions(&statuses).is_err());
+
+        statuses[0].code = 42;
+        assert!(check_three_digits(&statuses).is_err());
+
+        let statuses: Vec<Status> = all_statuses()
+            .into_iter()
+            .filter(|s| s.code != 404)
+            .collect();
+        assert_eq!(
+            check_class_counts(&statuses),
+            Err("4xx has 28 registered codes, expected 29".to_string())
+        );
+    }
+}